@@ -1,34 +1,307 @@
 //! HTTP client for the Asana API.
 
+use std::path::Path;
+use std::pin::pin;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures::stream::{self, Stream, TryStreamExt};
 use serde::de::DeserializeOwned;
 
-use crate::types::{DataWrapper, ListWrapper};
+use crate::types::{
+    BatchResult, DataWrapper, EventsPage, LimitedList, ListWrapper, PlannedRequest,
+};
 use crate::Error;
 
 const BASE_URL: &str = "https://app.asana.com/api/1.0";
+const OAUTH_TOKEN_URL: &str = "https://app.asana.com/-/oauth_token";
 const ENV_VAR: &str = "ASANA_TOKEN";
+const TOKEN_FILE_ENV_VAR: &str = "ASANA_TOKEN_FILE";
+const TIMEOUT_ENV_VAR: &str = "ASANA_TIMEOUT_SECS";
+const BASE_URL_ENV_VAR: &str = "ASANA_BASE_URL";
+
+/// Default `User-Agent` sent with every request.
+pub const DEFAULT_USER_AGENT: &str = concat!("asanamcp/", env!("CARGO_PKG_VERSION"));
+
+/// Value of the `X-Asana-Client-Lib` header Asana uses to attribute API traffic.
+const CLIENT_LIB: &str = concat!(
+    "language=Rust&version=",
+    env!("CARGO_PKG_VERSION"),
+    "&product=asanamcp"
+);
+
+/// Default overall timeout for a single request.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Default timeout for establishing a connection.
+pub const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Page size requested by paginated fetches; 100 is the most Asana returns per page.
+pub const DEFAULT_PAGE_SIZE: usize = 100;
+
+/// Maximum number of actions Asana accepts in a single `/batch` request.
+pub const MAX_BATCH_ACTIONS: usize = 10;
+
+/// How long to wait before retrying a rate-limited request when Asana omits `Retry-After`.
+const DEFAULT_RATE_LIMIT_WAIT: Duration = Duration::from_secs(1);
+
+/// Settings used to (re)build the underlying HTTP client.
+#[derive(Debug, Clone)]
+struct HttpConfig {
+    connect_timeout: Duration,
+    pool_max_idle_per_host: Option<usize>,
+    http2_prior_knowledge: bool,
+    proxy: Option<reqwest::Proxy>,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        Self {
+            connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+            pool_max_idle_per_host: None,
+            http2_prior_knowledge: false,
+            proxy: None,
+        }
+    }
+}
+
+/// Rate-limit state reported by Asana in response headers.
+///
+/// Each field is `None` when the corresponding header was absent.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RateLimitInfo {
+    /// Total requests allowed in the current window (`X-RateLimit-Limit`).
+    pub limit: Option<u64>,
+    /// Requests left in the current window (`X-RateLimit-Remaining`).
+    pub remaining: Option<u64>,
+    /// When the window resets, as reported by Asana (`X-RateLimit-Reset`).
+    pub reset: Option<u64>,
+}
+
+impl RateLimitInfo {
+    /// Parse rate-limit headers, returning `None` if none are present.
+    fn from_headers(headers: &reqwest::header::HeaderMap) -> Option<Self> {
+        let parse = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<u64>().ok())
+        };
+
+        let info = Self {
+            limit: parse("x-ratelimit-limit"),
+            remaining: parse("x-ratelimit-remaining"),
+            reset: parse("x-ratelimit-reset"),
+        };
+
+        if info == Self::default() {
+            None
+        } else {
+            Some(info)
+        }
+    }
+}
+
+/// OAuth credentials used to refresh an expired access token.
+///
+/// Shared between clones of a client, so a refresh made through one clone is used by all.
+struct OAuth {
+    client_id: String,
+    client_secret: String,
+    token_url: String,
+    tokens: tokio::sync::Mutex<OAuthTokens>,
+}
+
+struct OAuthTokens {
+    access_token: String,
+    refresh_token: String,
+}
+
+/// Successful response from the OAuth token endpoint.
+#[derive(serde::Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    /// Asana usually keeps the existing refresh token, but honor a new one if sent.
+    refresh_token: Option<String>,
+}
+
+/// Error response from the OAuth token endpoint.
+#[derive(serde::Deserialize)]
+struct TokenError {
+    error: String,
+    error_description: Option<String>,
+}
+
+impl std::fmt::Debug for OAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Tokens and the client secret are deliberately left out
+        f.debug_struct("OAuth")
+            .field("client_id", &self.client_id)
+            .field("token_url", &self.token_url)
+            .finish_non_exhaustive()
+    }
+}
+
+impl OAuth {
+    /// Returns the current access token.
+    async fn access_token(&self) -> String {
+        self.tokens.lock().await.access_token.clone()
+    }
+
+    /// Exchange the refresh token for a new access token.
+    ///
+    /// `stale` is the access token that was rejected. If another request already
+    /// replaced it while this one waited for the lock, no second refresh is made.
+    async fn refresh(
+        &self,
+        http: &reqwest::Client,
+        user_agent: &reqwest::header::HeaderValue,
+        stale: &str,
+        timeout: Duration,
+    ) -> Result<(), Error> {
+        let mut tokens = self.tokens.lock().await;
+        if tokens.access_token != stale {
+            return Ok(());
+        }
+
+        let response = http
+            .post(&self.token_url)
+            .header(reqwest::header::USER_AGENT, user_agent)
+            .timeout(timeout)
+            .form(&[
+                ("grant_type", "refresh_token"),
+                ("client_id", self.client_id.as_str()),
+                ("client_secret", self.client_secret.as_str()),
+                ("refresh_token", tokens.refresh_token.as_str()),
+            ])
+            .send()
+            .await?;
+
+        let status = response.status();
+        let body = response.text().await?;
+        if !status.is_success() {
+            let reason = match serde_json::from_str::<TokenError>(&body) {
+                Ok(e) => e.error_description.unwrap_or(e.error),
+                Err(_) => format!("HTTP {}", status.as_u16()),
+            };
+            return Err(Error::Unauthorized(format!(
+                "failed to refresh OAuth token: {}",
+                reason
+            )));
+        }
+
+        let refreshed: TokenResponse = serde_json::from_str(&body)?;
+        tokens.access_token = refreshed.access_token;
+        if let Some(refresh_token) = refreshed.refresh_token {
+            tokens.refresh_token = refresh_token;
+        }
+        Ok(())
+    }
+}
 
 /// Client for interacting with the Asana API.
+///
+/// Clones share rate-limit state, so [`AsanaClient::last_rate_limit`] reflects
+/// requests made through any clone.
 #[derive(Debug, Clone)]
 pub struct AsanaClient {
     http: reqwest::Client,
+    http_config: HttpConfig,
+    headers: reqwest::header::HeaderMap,
+    user_agent: reqwest::header::HeaderValue,
     base_url: String,
+    timeout: Duration,
+    retry_on_rate_limit: bool,
+    max_retries: u32,
+    retry_base_delay: Duration,
+    last_rate_limit: Arc<Mutex<Option<RateLimitInfo>>>,
+    oauth: Option<Arc<OAuth>>,
+    /// Output options (`opt_expand`, `opt_pretty`) added to every GET request.
+    output_options: Vec<(&'static str, String)>,
+    /// Writes recorded instead of sent, when in dry-run mode.
+    dry_run: Option<Arc<Mutex<Vec<PlannedRequest>>>>,
 }
 
 impl AsanaClient {
     /// Create a new client from the `ASANA_TOKEN` environment variable.
     ///
+    /// When `ASANA_TOKEN` is unset or empty, the token is read from the file named by
+    /// `ASANA_TOKEN_FILE` instead (see [`AsanaClient::from_token_file`]). The optional
+    /// `ASANA_TIMEOUT_SECS` variable overrides the default request timeout, and
+    /// `ASANA_BASE_URL` points the client at a proxy or gateway instead of Asana.
+    ///
     /// # Errors
     ///
-    /// Returns an error if `ASANA_TOKEN` is not set or is empty.
+    /// Returns an error if neither variable provides a token, if the token file cannot
+    /// be read, if `ASANA_TIMEOUT_SECS` is not a positive integer, or if
+    /// `ASANA_BASE_URL` is not an http(s) URL.
     pub fn from_env() -> Result<Self, Error> {
-        let token = std::env::var(ENV_VAR).map_err(|_| Error::MissingToken)?;
+        let token = resolve_env_token(
+            std::env::var(ENV_VAR).ok(),
+            std::env::var(TOKEN_FILE_ENV_VAR).ok(),
+        )?;
+
+        Self::new(&token)?.with_env_settings()
+    }
+
+    /// Create a client for a named account from `ASANA_<NAME>_TOKEN`.
+    ///
+    /// `<NAME>` is the account name uppercased with `-` replaced by `_`, so account
+    /// `acme-corp` reads `ASANA_ACME_CORP_TOKEN`, falling back to `ASANA_ACME_CORP_TOKEN_FILE`.
+    /// The shared `ASANA_TIMEOUT_SECS` and `ASANA_BASE_URL` settings apply as in
+    /// [`AsanaClient::from_env`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if neither account variable provides a token, or if the shared
+    /// settings are invalid.
+    pub fn from_env_account(name: &str) -> Result<Self, Error> {
+        let token_var = account_env_var(name, "TOKEN");
+        let token_file_var = account_env_var(name, "TOKEN_FILE");
+        let token = resolve_env_token(
+            std::env::var(&token_var).ok(),
+            std::env::var(&token_file_var).ok(),
+        )
+        .map_err(|e| match e {
+            Error::MissingToken => Error::Config(format!(
+                "account {:?} requires {} or {}",
+                name, token_var, token_file_var
+            )),
+            e => e,
+        })?;
+
+        Self::new(&token)?.with_env_settings()
+    }
+
+    /// Apply the `ASANA_TIMEOUT_SECS` and `ASANA_BASE_URL` environment settings.
+    fn with_env_settings(self) -> Result<Self, Error> {
+        let mut client = self;
+
+        if let Ok(value) = std::env::var(TIMEOUT_ENV_VAR) {
+            if !value.is_empty() {
+                client = client.with_timeout(parse_timeout_secs(&value)?);
+            }
+        }
 
-        if token.is_empty() {
-            return Err(Error::MissingToken);
+        if let Ok(value) = std::env::var(BASE_URL_ENV_VAR) {
+            if !value.is_empty() {
+                client = client.with_base_url(&parse_base_url(&value)?);
+            }
         }
 
-        Self::new(&token)
+        Ok(client)
+    }
+
+    /// Create a new client with the access token stored in a file.
+    ///
+    /// Trailing newlines and whitespace are trimmed, so files written by secret
+    /// managers or `echo` work as-is.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or contains no token.
+    pub fn from_token_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::new(&read_token_file(path.as_ref())?)
     }
 
     /// Create a new client with the given access token.
@@ -37,23 +310,70 @@ impl AsanaClient {
     ///
     /// Returns an error if the HTTP client cannot be initialized.
     pub fn new(token: &str) -> Result<Self, Error> {
-        use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION};
+        use reqwest::header::{HeaderMap, AUTHORIZATION};
 
         let mut headers = HeaderMap::new();
-        let auth_value = format!("Bearer {}", token);
-        headers.insert(
-            AUTHORIZATION,
-            HeaderValue::from_str(&auth_value).map_err(|_| Error::InvalidToken)?,
-        );
+        headers.insert(AUTHORIZATION, bearer_header(token)?);
+
+        Self::with_headers(headers, None)
+    }
+
+    /// Create a client authenticated with an OAuth access token.
+    ///
+    /// When Asana rejects the access token with a 401, the client exchanges the refresh
+    /// token for a new access token at Asana's token endpoint and retries the request
+    /// once. The refreshed token is kept for later requests, including those made
+    /// through clones of this client.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the access token contains invalid characters or the HTTP
+    /// client cannot be initialized.
+    pub fn with_oauth(
+        access_token: &str,
+        refresh_token: &str,
+        client_id: &str,
+        client_secret: &str,
+    ) -> Result<Self, Error> {
+        bearer_header(access_token)?;
+
+        let oauth = OAuth {
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            token_url: OAUTH_TOKEN_URL.to_string(),
+            tokens: tokio::sync::Mutex::new(OAuthTokens {
+                access_token: access_token.to_string(),
+                refresh_token: refresh_token.to_string(),
+            }),
+        };
+
+        Self::with_headers(reqwest::header::HeaderMap::new(), Some(oauth))
+    }
 
-        let http = reqwest::Client::builder()
-            .default_headers(headers)
-            .build()
-            .map_err(Error::Http)?;
+    fn with_headers(
+        mut headers: reqwest::header::HeaderMap,
+        oauth: Option<OAuth>,
+    ) -> Result<Self, Error> {
+        use reqwest::header::HeaderValue;
+
+        headers.insert("x-asana-client-lib", HeaderValue::from_static(CLIENT_LIB));
+        let http_config = HttpConfig::default();
+        let http = build_http(&headers, &http_config)?;
 
         Ok(Self {
             http,
+            http_config,
+            headers,
+            user_agent: HeaderValue::from_static(DEFAULT_USER_AGENT),
             base_url: BASE_URL.to_string(),
+            timeout: DEFAULT_TIMEOUT,
+            retry_on_rate_limit: false,
+            max_retries: 0,
+            retry_base_delay: Duration::ZERO,
+            last_rate_limit: Arc::new(Mutex::new(None)),
+            oauth: oauth.map(Arc::new),
+            output_options: Vec::new(),
+            dry_run: None,
         })
     }
 
@@ -69,6 +389,184 @@ impl AsanaClient {
         self
     }
 
+    /// Set the `User-Agent` sent with each request (default `asanamcp/<version>`).
+    ///
+    /// Integrations embedding this client can identify themselves so Asana admins can
+    /// tell which app is making calls.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `user_agent` is not a valid header value.
+    pub fn with_user_agent(mut self, user_agent: &str) -> Result<Self, Error> {
+        self.user_agent = reqwest::header::HeaderValue::from_str(user_agent)
+            .map_err(|_| Error::Config(format!("invalid User-Agent: {:?}", user_agent)))?;
+        Ok(self)
+    }
+
+    /// Returns the `User-Agent` sent with each request.
+    pub fn user_agent(&self) -> &str {
+        self.user_agent.to_str().unwrap_or_default()
+    }
+
+    /// Set a custom OAuth token endpoint (primarily for testing).
+    ///
+    /// Has no effect on clients created without [`AsanaClient::with_oauth`], or once
+    /// the client has been cloned.
+    #[doc(hidden)]
+    pub fn with_oauth_token_url(mut self, token_url: &str) -> Self {
+        if let Some(oauth) = self.oauth.as_mut().and_then(Arc::get_mut) {
+            oauth.token_url = token_url.to_string();
+        }
+        self
+    }
+
+    /// Fully expand these nested objects (e.g. `assignee`) in GET responses.
+    ///
+    /// Sent as Asana's `opt_expand` on every GET, alongside any `opt_fields`. An empty
+    /// list clears a previous expansion.
+    pub fn with_opt_expand(mut self, fields: &[&str]) -> Self {
+        self.output_options.retain(|(key, _)| *key != "opt_expand");
+        if !fields.is_empty() {
+            self.output_options.push(("opt_expand", fields.join(",")));
+        }
+        self
+    }
+
+    /// Ask Asana to pretty-print GET responses (`opt_pretty`), useful when debugging
+    /// raw traffic through a proxy.
+    pub fn with_opt_pretty(mut self, pretty: bool) -> Self {
+        self.output_options.retain(|(key, _)| *key != "opt_pretty");
+        if pretty {
+            self.output_options.push(("opt_pretty", "true".to_string()));
+        }
+        self
+    }
+
+    /// Record writes (POST, PUT, DELETE) instead of sending them.
+    ///
+    /// Each write is added to [`AsanaClient::planned_requests`] and fails with
+    /// [`Error::DryRun`], so callers stop at their first write. Reads are still sent.
+    /// Clones of the returned client share the same record.
+    pub fn with_dry_run(mut self) -> Self {
+        self.dry_run = Some(Arc::default());
+        self
+    }
+
+    /// The writes recorded in dry-run mode, in the order they were attempted.
+    pub fn planned_requests(&self) -> Vec<PlannedRequest> {
+        self.dry_run
+            .as_ref()
+            .map(|log| {
+                log.lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner())
+                    .clone()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Set the overall timeout for each request (default 30s).
+    ///
+    /// This bounds the whole request, from connecting through reading the body.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set the timeout for establishing a connection (default 10s).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying HTTP client cannot be rebuilt.
+    pub fn with_connect_timeout(mut self, connect_timeout: Duration) -> Result<Self, Error> {
+        self.http_config.connect_timeout = connect_timeout;
+        self.rebuild_http()
+    }
+
+    /// Cap the number of idle connections kept open per host (default: no cap).
+    ///
+    /// Integrations issuing many concurrent requests may want to tune this, but the
+    /// default suits most uses.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying HTTP client cannot be rebuilt.
+    pub fn with_pool_max_idle_per_host(mut self, max_idle: usize) -> Result<Self, Error> {
+        self.http_config.pool_max_idle_per_host = Some(max_idle);
+        self.rebuild_http()
+    }
+
+    /// Speak HTTP/2 without negotiating it first (default: off).
+    ///
+    /// By default HTTP/2 is used when the server offers it during the TLS handshake,
+    /// and HTTP/1.1 otherwise. Enable this only for endpoints known to accept HTTP/2
+    /// directly, such as a cleartext (h2c) gateway set with `ASANA_BASE_URL`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying HTTP client cannot be rebuilt.
+    pub fn with_http2_prior_knowledge(mut self, enabled: bool) -> Result<Self, Error> {
+        self.http_config.http2_prior_knowledge = enabled;
+        self.rebuild_http()
+    }
+
+    /// Send all requests through the proxy at `url` (e.g. `http://proxy.corp:8080`).
+    ///
+    /// Without this, the standard `HTTPS_PROXY`, `HTTP_PROXY`, and `ALL_PROXY` variables
+    /// are honored. An explicit proxy takes precedence over them, though hosts listed in
+    /// `NO_PROXY` still bypass it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `url` is not a valid proxy URL or the HTTP client cannot be rebuilt.
+    pub fn with_proxy(mut self, url: &str) -> Result<Self, Error> {
+        let proxy = reqwest::Proxy::all(url)
+            .map_err(|e| Error::Config(format!("invalid proxy URL {:?}: {}", url, e)))?
+            .no_proxy(reqwest::NoProxy::from_env());
+        self.http_config.proxy = Some(proxy);
+        self.rebuild_http()
+    }
+
+    fn rebuild_http(mut self) -> Result<Self, Error> {
+        self.http = build_http(&self.headers, &self.http_config)?;
+        Ok(self)
+    }
+
+    /// Returns the overall timeout applied to each request.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Sleep and retry once when Asana responds with HTTP 429.
+    ///
+    /// The wait honors the `Retry-After` header. If the retry is also rate limited,
+    /// the request fails with [`Error::RateLimited`].
+    pub fn with_retry_on_rate_limit(mut self, enabled: bool) -> Self {
+        self.retry_on_rate_limit = enabled;
+        self
+    }
+
+    /// Retry idempotent GET requests on transient failures.
+    ///
+    /// 5xx responses, timeouts, and connection errors are retried up to `max_retries`
+    /// times with exponential backoff (`base_delay * 2^attempt`) plus jitter. Writes
+    /// (POST/PUT/DELETE) are never retried, to avoid duplicate creates.
+    pub fn with_retry(mut self, max_retries: u32, base_delay: Duration) -> Self {
+        self.max_retries = max_retries;
+        self.retry_base_delay = base_delay;
+        self
+    }
+
+    /// Returns the rate-limit headers from the most recent response that included them.
+    ///
+    /// Callers making many writes can use this to pace themselves before Asana starts
+    /// returning 429s.
+    pub fn last_rate_limit(&self) -> Option<RateLimitInfo> {
+        *self
+            .last_rate_limit
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
     /// Make a GET request to the API and deserialize the response.
     ///
     /// The `path` should be the API endpoint path without the base URL (e.g., "/users/me").
@@ -79,7 +577,11 @@ impl AsanaClient {
     {
         let url = format!("{}{}", self.base_url, path);
 
-        let response = self.http.get(&url).query(query).send().await?;
+        let response = self
+            .execute(true, || {
+                self.http.get(&url).query(query).query(&self.output_options)
+            })
+            .await?;
 
         self.handle_response::<DataWrapper<T>>(response)
             .await
@@ -99,7 +601,11 @@ impl AsanaClient {
     {
         let url = format!("{}{}", self.base_url, path);
 
-        let response = self.http.get(&url).query(query).send().await?;
+        let response = self
+            .execute(true, || {
+                self.http.get(&url).query(query).query(&self.output_options)
+            })
+            .await?;
 
         self.handle_response::<ListWrapper<T>>(response).await
     }
@@ -107,33 +613,112 @@ impl AsanaClient {
     /// Make a GET request and collect all pages of results.
     ///
     /// This will automatically follow pagination until all results are collected.
+    /// Pages of [`DEFAULT_PAGE_SIZE`] are requested unless `query` includes its own `limit`.
     pub async fn get_all<T>(&self, path: &str, query: &[(&str, &str)]) -> Result<Vec<T>, Error>
     where
         T: DeserializeOwned,
     {
-        let mut all_items = Vec::new();
-        let mut offset: Option<String> = None;
+        self.stream_all(path, query).try_collect().await
+    }
 
-        loop {
-            let query_with_offset: Vec<(&str, &str)> = match &offset {
-                Some(off) => {
-                    let mut q = query.to_vec();
-                    q.push(("offset", off.as_str()));
-                    q
-                }
-                None => query.to_vec(),
-            };
+    /// Make a GET request and stream results item by item, following pagination.
+    ///
+    /// Each page is fetched only once the items from the previous page have been consumed,
+    /// so dropping the stream early avoids requesting the remaining pages.
+    /// Pages of [`DEFAULT_PAGE_SIZE`] are requested unless `query` includes its own `limit`.
+    pub fn stream_all<'a, T>(
+        &'a self,
+        path: &'a str,
+        query: &'a [(&'a str, &'a str)],
+    ) -> impl Stream<Item = Result<T, Error>> + 'a
+    where
+        T: DeserializeOwned + 'a,
+    {
+        self.pages(path, query, DEFAULT_PAGE_SIZE)
+            .map_ok(|page: ListWrapper<T>| stream::iter(page.data.into_iter().map(Ok)))
+            .try_flatten()
+    }
 
-            let wrapper: ListWrapper<T> = self.get_list(path, &query_with_offset).await?;
-            all_items.extend(wrapper.data);
+    /// Make a GET request and collect pages of results, stopping once `max_items` are collected.
+    ///
+    /// With `max_items` of `None` this follows pagination to the end, like [`get_all`].
+    /// When `max_items` is below [`DEFAULT_PAGE_SIZE`], only that many items are requested per page.
+    /// The returned [`LimitedList::has_more`] is set when results were cut off.
+    ///
+    /// [`get_all`]: AsanaClient::get_all
+    pub async fn get_all_limited<T>(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+        max_items: Option<usize>,
+    ) -> Result<LimitedList<T>, Error>
+    where
+        T: DeserializeOwned,
+    {
+        // Request full pages (or just enough for a small limit) unless the caller chose a size
+        let page_size = max_items.map_or(DEFAULT_PAGE_SIZE, |max| max.clamp(1, DEFAULT_PAGE_SIZE));
+        let mut pages = pin!(self.pages(path, query, page_size));
+        let mut all_items = Vec::new();
 
-            offset = wrapper.next_page.map(|next| next.offset);
-            if offset.is_none() {
-                break;
+        while let Some(page) = pages.try_next().await? {
+            let has_next = page.next_page.is_some();
+            all_items.extend(page.data);
+
+            if let Some(max) = max_items {
+                if all_items.len() >= max {
+                    let has_more = all_items.len() > max || has_next;
+                    all_items.truncate(max);
+                    return Ok(LimitedList {
+                        data: all_items,
+                        has_more,
+                    });
+                }
             }
         }
 
-        Ok(all_items)
+        Ok(LimitedList {
+            data: all_items,
+            has_more: false,
+        })
+    }
+
+    /// Stream successive pages of a list endpoint until Asana reports no next page.
+    fn pages<'a, T>(
+        &'a self,
+        path: &'a str,
+        query: &'a [(&'a str, &'a str)],
+        page_size: usize,
+    ) -> impl Stream<Item = Result<ListWrapper<T>, Error>> + 'a
+    where
+        T: DeserializeOwned + 'a,
+    {
+        let page_size = page_size.to_string();
+        let has_page_size = query.iter().any(|(k, _)| *k == "limit");
+
+        // State is the offset of the next page to fetch; `None` once pagination is exhausted
+        stream::try_unfold(Some(None::<String>), move |state| {
+            let page_size = page_size.clone();
+            async move {
+                let Some(offset) = state else {
+                    return Ok(None);
+                };
+
+                let mut page_query = query.to_vec();
+                if !has_page_size {
+                    page_query.push(("limit", page_size.as_str()));
+                }
+                if let Some(off) = &offset {
+                    page_query.push(("offset", off.as_str()));
+                }
+
+                let page: ListWrapper<T> = self.get_list(path, &page_query).await?;
+                let next = page
+                    .next_page
+                    .as_ref()
+                    .map(|next| Some(next.offset.clone()));
+                Ok(Some((page, next)))
+            }
+        })
     }
 
     /// Make a POST request to create a resource and deserialize the response.
@@ -145,15 +730,98 @@ impl AsanaClient {
         T: DeserializeOwned,
         B: serde::Serialize,
     {
+        self.plan("POST", path, Some(body))?;
+        let url = format!("{}{}", self.base_url, path);
+
+        let response = self
+            .execute(false, || self.http.post(&url).json(body))
+            .await?;
+
+        self.handle_response::<DataWrapper<T>>(response)
+            .await
+            .map(|wrapper| wrapper.data)
+    }
+
+    /// Make a multipart/form-data POST request and deserialize the response.
+    ///
+    /// Some endpoints (e.g. `/attachments`) only accept form data rather than JSON.
+    /// Each `(name, value)` pair is sent as a text part.
+    pub async fn post_multipart<T>(&self, path: &str, fields: &[(&str, &str)]) -> Result<T, Error>
+    where
+        T: DeserializeOwned,
+    {
+        let form: serde_json::Map<_, _> = fields
+            .iter()
+            .map(|(name, value)| (name.to_string(), serde_json::json!(value)))
+            .collect();
+        self.plan("POST", path, Some(&form))?;
         let url = format!("{}{}", self.base_url, path);
 
-        let response = self.http.post(&url).json(body).send().await?;
+        // A Form can't be cloned, so each attempt builds its own
+        let response = self
+            .execute(false, || {
+                let form = fields
+                    .iter()
+                    .fold(reqwest::multipart::Form::new(), |form, (name, value)| {
+                        form.text(name.to_string(), value.to_string())
+                    });
+                self.http.post(&url).multipart(form)
+            })
+            .await?;
 
         self.handle_response::<DataWrapper<T>>(response)
             .await
             .map(|wrapper| wrapper.data)
     }
 
+    /// Submit several actions in one round trip via Asana's `/batch` endpoint.
+    ///
+    /// Each action should serialize to `{"method", "relative_path", "data"?, "options"?}`.
+    /// Asana accepts at most [`MAX_BATCH_ACTIONS`] actions per request. Results are
+    /// returned in action order; a failed action is reported by its `status_code` rather
+    /// than as an `Err`.
+    pub async fn batch<A>(&self, actions: &[A]) -> Result<Vec<BatchResult>, Error>
+    where
+        A: serde::Serialize,
+    {
+        let body = serde_json::json!({"data": {"actions": actions}});
+        self.post("/batch", &body).await
+    }
+
+    /// Fetch events on a resource (project, task, etc.) since a sync token.
+    ///
+    /// Asana answers a missing or expired `sync` token with `412 Precondition Failed`
+    /// and a fresh token. That case is returned as an empty [`EventsPage`] carrying the
+    /// new token, so callers can simply start polling from it.
+    pub async fn get_events(
+        &self,
+        resource: &str,
+        sync: Option<&str>,
+    ) -> Result<EventsPage, Error> {
+        let url = format!("{}/events", self.base_url);
+        let mut query = vec![("resource", resource)];
+        if let Some(sync) = sync {
+            query.push(("sync", sync));
+        }
+
+        let response = self
+            .execute(true, || self.http.get(&url).query(&query))
+            .await?;
+
+        if response.status() == reqwest::StatusCode::PRECONDITION_FAILED {
+            self.record_rate_limit(&response);
+            let body = response.text().await?;
+            let page: EventsPage = serde_json::from_str(&body)?;
+            return Ok(EventsPage {
+                data: Vec::new(),
+                has_more: false,
+                ..page
+            });
+        }
+
+        self.handle_response(response).await
+    }
+
     /// Make a PUT request to update a resource and deserialize the response.
     ///
     /// The `path` should be the API endpoint path without the base URL.
@@ -163,26 +831,52 @@ impl AsanaClient {
         T: DeserializeOwned,
         B: serde::Serialize,
     {
+        self.plan("PUT", path, Some(body))?;
         let url = format!("{}{}", self.base_url, path);
 
-        let response = self.http.put(&url).json(body).send().await?;
+        let response = self
+            .execute(false, || self.http.put(&url).json(body))
+            .await?;
 
         self.handle_response::<DataWrapper<T>>(response)
             .await
             .map(|wrapper| wrapper.data)
     }
 
+    /// Make a POST request to an action endpoint that may or may not return a record.
+    ///
+    /// Returns `None` for `204 No Content`, an empty body, or an empty `{"data": {}}`
+    /// block, and the deserialized `data` otherwise.
+    pub async fn post_optional<T, B>(&self, path: &str, body: &B) -> Result<Option<T>, Error>
+    where
+        T: DeserializeOwned,
+        B: serde::Serialize,
+    {
+        self.plan("POST", path, Some(body))?;
+        let url = format!("{}{}", self.base_url, path);
+
+        let response = self
+            .execute(false, || self.http.post(&url).json(body))
+            .await?;
+
+        self.handle_optional_response(response).await
+    }
+
     /// Make a POST request that expects no response body (e.g., relationship operations).
     ///
+    /// Any body returned on success is ignored, as is the status (`200` or `204`).
     /// The `path` should be the API endpoint path without the base URL.
     /// The `body` will be serialized as JSON in the request body.
     pub async fn post_empty<B>(&self, path: &str, body: &B) -> Result<(), Error>
     where
         B: serde::Serialize,
     {
+        self.plan("POST", path, Some(body))?;
         let url = format!("{}{}", self.base_url, path);
 
-        let response = self.http.post(&url).json(body).send().await?;
+        let response = self
+            .execute(false, || self.http.post(&url).json(body))
+            .await?;
 
         self.handle_empty_response(response).await
     }
@@ -191,9 +885,10 @@ impl AsanaClient {
     ///
     /// The `path` should be the API endpoint path without the base URL.
     pub async fn delete(&self, path: &str) -> Result<(), Error> {
+        self.plan::<()>("DELETE", path, None)?;
         let url = format!("{}{}", self.base_url, path);
 
-        let response = self.http.delete(&url).send().await?;
+        let response = self.execute(false, || self.http.delete(&url)).await?;
 
         self.handle_empty_response(response).await
     }
@@ -206,84 +901,457 @@ impl AsanaClient {
     where
         B: serde::Serialize,
     {
+        self.plan("DELETE", path, Some(body))?;
         let url = format!("{}{}", self.base_url, path);
 
-        let response = self.http.delete(&url).json(body).send().await?;
+        let response = self
+            .execute(false, || self.http.delete(&url).json(body))
+            .await?;
 
         self.handle_empty_response(response).await
     }
 
-    /// Handle an API response, converting errors as appropriate.
-    async fn handle_response<T>(&self, response: reqwest::Response) -> Result<T, Error>
-    where
-        T: DeserializeOwned,
-    {
-        let status = response.status();
-
-        if status.is_success() {
-            let body = response.text().await?;
-            serde_json::from_str(&body).map_err(Error::Parse)
-        } else {
-            Err(self.error_from_response(response).await)
+    /// Send a request to any API path and return the response body as raw JSON.
+    ///
+    /// `method` is an HTTP method name such as `"GET"` or `"POST"`. The `body` is sent
+    /// as-is, so it should include the `{"data": ...}` envelope. GET requests are retried
+    /// like other reads and other methods are recorded in dry-run mode like other writes.
+    /// An empty response body (e.g. `204 No Content`) is returned as `null`.
+    pub async fn request_json(
+        &self,
+        method: &'static str,
+        path: &str,
+        query: &[(&str, &str)],
+        body: Option<&serde_json::Value>,
+    ) -> Result<serde_json::Value, Error> {
+        let http_method = reqwest::Method::from_bytes(method.as_bytes())
+            .map_err(|_| Error::Config(format!("invalid HTTP method: {}", method)))?;
+        let read = http_method == reqwest::Method::GET;
+        if !read {
+            self.plan(method, path, body)?;
         }
-    }
+        let url = format!("{}{}", self.base_url, path);
 
-    /// Handle an API response that should have no body.
-    async fn handle_empty_response(&self, response: reqwest::Response) -> Result<(), Error> {
-        let status = response.status();
+        let response = self
+            .execute(read, || {
+                let request = self.http.request(http_method.clone(), &url).query(query);
+                match body {
+                    Some(body) => request.json(body),
+                    None => request,
+                }
+            })
+            .await?;
 
-        if status.is_success() {
-            Ok(())
-        } else {
-            Err(self.error_from_response(response).await)
+        self.record_rate_limit(&response);
+        if !response.status().is_success() {
+            return Err(self.error_from_response(response).await);
         }
+        let body = response.text().await?;
+        if body.trim().is_empty() {
+            return Ok(serde_json::Value::Null);
+        }
+        serde_json::from_str(&body).map_err(Error::Parse)
     }
 
-    /// Convert an error response to an Error.
-    async fn error_from_response(&self, response: reqwest::Response) -> Error {
-        let status = response.status();
-        let body = response.text().await.unwrap_or_default();
-
-        if status == reqwest::StatusCode::NOT_FOUND {
-            let message =
-                extract_error_message(&body).unwrap_or_else(|| "resource not found".to_string());
-            Error::NotFound(message)
-        } else {
-            let message = extract_error_message(&body).unwrap_or_else(|| {
-                format!(
-                    "HTTP {} {}",
+    /// In dry-run mode, record a write and return the error that stops it from being sent.
+    fn plan<B>(&self, method: &'static str, path: &str, body: Option<&B>) -> Result<(), Error>
+    where
+        B: serde::Serialize,
+    {
+        let Some(log) = &self.dry_run else {
+            return Ok(());
+        };
+        let body = body.map(serde_json::to_value).transpose()?;
+        log.lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .push(PlannedRequest {
+                method,
+                path: path.to_string(),
+                body,
+            });
+        Err(Error::DryRun {
+            method,
+            path: path.to_string(),
+        })
+    }
+
+    /// Send a request, applying the client's retry policies.
+    ///
+    /// A 429 is retried once after `Retry-After` if rate-limit retries are enabled.
+    /// Idempotent requests are additionally retried on 5xx responses, timeouts, and
+    /// connection errors. OAuth clients refresh the access token and retry once on a
+    /// 401. The request is rebuilt for each attempt since a sent `RequestBuilder` is
+    /// consumed.
+    async fn execute<F>(&self, idempotent: bool, build: F) -> Result<reqwest::Response, Error>
+    where
+        F: Fn() -> reqwest::RequestBuilder,
+    {
+        let max_retries = if idempotent { self.max_retries } else { 0 };
+        let mut attempt = 0;
+        let mut rate_limit_retried = false;
+        let mut oauth_refreshed = false;
+
+        loop {
+            let mut request = build().header(reqwest::header::USER_AGENT, &self.user_agent);
+            let access_token = match &self.oauth {
+                Some(oauth) => Some(oauth.access_token().await),
+                None => None,
+            };
+            if let Some(token) = &access_token {
+                request = request.bearer_auth(token);
+            }
+
+            match self.send(request).await {
+                Ok(response) => {
+                    let status = response.status();
+
+                    if status == reqwest::StatusCode::UNAUTHORIZED && !oauth_refreshed {
+                        if let (Some(oauth), Some(stale)) = (&self.oauth, &access_token) {
+                            oauth_refreshed = true;
+                            oauth
+                                .refresh(&self.http, &self.user_agent, stale, self.timeout)
+                                .await?;
+                            continue;
+                        }
+                    }
+
+                    if status == reqwest::StatusCode::TOO_MANY_REQUESTS
+                        && self.retry_on_rate_limit
+                        && !rate_limit_retried
+                    {
+                        rate_limit_retried = true;
+                        let wait = parse_retry_after(response.headers())
+                            .unwrap_or(DEFAULT_RATE_LIMIT_WAIT);
+                        tokio::time::sleep(wait).await;
+                        continue;
+                    }
+
+                    if status.is_server_error() && attempt < max_retries {
+                        tokio::time::sleep(self.backoff_delay(attempt)).await;
+                        attempt += 1;
+                        continue;
+                    }
+
+                    return Ok(response);
+                }
+                Err(e) if attempt < max_retries && (e.is_timeout() || e.is_connect()) => {
+                    tokio::time::sleep(self.backoff_delay(attempt)).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+
+    /// Send a single attempt of a request.
+    #[cfg(not(feature = "tracing"))]
+    async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        request.timeout(self.timeout).send().await
+    }
+
+    /// Send a single attempt of a request inside an `asana_request` span that records
+    /// the method, path, status, and duration.
+    #[cfg(feature = "tracing")]
+    async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        use tracing::Instrument;
+
+        let request = request.timeout(self.timeout).build()?;
+        let span = tracing::debug_span!(
+            "asana_request",
+            method = %request.method(),
+            path = request.url().path(),
+            status = tracing::field::Empty,
+            duration_ms = tracing::field::Empty,
+        );
+
+        let started = std::time::Instant::now();
+        let result = self.http.execute(request).instrument(span.clone()).await;
+        let duration_ms = started.elapsed().as_millis() as u64;
+        span.record("duration_ms", duration_ms);
+
+        match &result {
+            Ok(response) => {
+                let status = response.status().as_u16();
+                span.record("status", status);
+                tracing::debug!(parent: &span, status, duration_ms, "Asana request completed");
+            }
+            Err(e) => {
+                tracing::debug!(parent: &span, error = %e, duration_ms, "Asana request failed")
+            }
+        }
+        result
+    }
+
+    /// Compute the exponential backoff delay (with jitter) before retry number `attempt`.
+    fn backoff_delay(&self, attempt: u32) -> Duration {
+        let base = self.retry_base_delay;
+        let exponential = base.saturating_mul(2u32.saturating_pow(attempt.min(16)));
+        exponential + jitter(base)
+    }
+
+    /// Handle an API response, converting errors as appropriate.
+    async fn handle_response<T>(&self, response: reqwest::Response) -> Result<T, Error>
+    where
+        T: DeserializeOwned,
+    {
+        self.record_rate_limit(&response);
+        let status = response.status();
+
+        if status.is_success() {
+            let body = response.text().await?;
+            serde_json::from_str(&body).map_err(Error::Parse)
+        } else {
+            Err(self.error_from_response(response).await)
+        }
+    }
+
+    /// Handle an API response whose `data` may be absent or empty.
+    async fn handle_optional_response<T>(
+        &self,
+        response: reqwest::Response,
+    ) -> Result<Option<T>, Error>
+    where
+        T: DeserializeOwned,
+    {
+        self.record_rate_limit(&response);
+        let status = response.status();
+
+        if !status.is_success() {
+            return Err(self.error_from_response(response).await);
+        }
+
+        let body = response.text().await?;
+        if body.trim().is_empty() {
+            return Ok(None);
+        }
+        let wrapper: DataWrapper<serde_json::Value> =
+            serde_json::from_str(&body).map_err(Error::Parse)?;
+        match wrapper.data {
+            serde_json::Value::Null => Ok(None),
+            serde_json::Value::Object(map) if map.is_empty() => Ok(None),
+            data => serde_json::from_value(data).map(Some).map_err(Error::Parse),
+        }
+    }
+
+    /// Handle an API response whose body, if any, is ignored.
+    async fn handle_empty_response(&self, response: reqwest::Response) -> Result<(), Error> {
+        self.record_rate_limit(&response);
+        let status = response.status();
+
+        if status.is_success() {
+            Ok(())
+        } else {
+            Err(self.error_from_response(response).await)
+        }
+    }
+
+    /// Remember the rate-limit headers from a response, if it has any.
+    fn record_rate_limit(&self, response: &reqwest::Response) {
+        if let Some(info) = RateLimitInfo::from_headers(response.headers()) {
+            *self
+                .last_rate_limit
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(info);
+        }
+    }
+
+    /// Convert an error response to an Error.
+    async fn error_from_response(&self, response: reqwest::Response) -> Error {
+        let status = response.status();
+
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return Error::RateLimited {
+                retry_after: parse_retry_after(response.headers()),
+            };
+        }
+
+        let body = response.text().await.unwrap_or_default();
+        let detail = extract_error(&body);
+        let message_or = |fallback: &str| {
+            detail
+                .as_ref()
+                .map(|d| d.message.clone())
+                .unwrap_or_else(|| fallback.to_string())
+        };
+
+        match status {
+            reqwest::StatusCode::NOT_FOUND => Error::NotFound(message_or("resource not found")),
+            reqwest::StatusCode::UNAUTHORIZED => Error::Unauthorized(message_or("not authorized")),
+            reqwest::StatusCode::FORBIDDEN => Error::Forbidden(message_or("access denied")),
+            reqwest::StatusCode::BAD_REQUEST => Error::Validation {
+                message: message_or("invalid request"),
+                help: detail.and_then(|d| d.help),
+            },
+            _ => {
+                let fallback = format!(
+                    "HTTP {} {}",
                     status.as_u16(),
                     status.canonical_reason().unwrap_or("")
-                )
-            });
-            Error::Api { message }
+                );
+                let message = match detail {
+                    // Asana includes a phrase on server errors for support to look up
+                    Some(ApiErrorDetail {
+                        message,
+                        phrase: Some(phrase),
+                        ..
+                    }) => format!("{} (error phrase: {})", message, phrase),
+                    Some(d) => d.message,
+                    None => fallback,
+                };
+                Error::Api { message }
+            }
         }
     }
 }
 
-/// Extract the error message from an Asana API error response.
-fn extract_error_message(body: &str) -> Option<String> {
-    #[derive(serde::Deserialize)]
-    struct ErrorResponse {
-        errors: Vec<ErrorDetail>,
+/// Build the underlying HTTP client with the default headers and connection settings.
+fn build_http(
+    headers: &reqwest::header::HeaderMap,
+    config: &HttpConfig,
+) -> Result<reqwest::Client, Error> {
+    let mut builder = reqwest::Client::builder()
+        .default_headers(headers.clone())
+        .connect_timeout(config.connect_timeout);
+    if let Some(max_idle) = config.pool_max_idle_per_host {
+        builder = builder.pool_max_idle_per_host(max_idle);
+    }
+    if config.http2_prior_knowledge {
+        builder = builder.http2_prior_knowledge();
+    }
+    if let Some(proxy) = &config.proxy {
+        builder = builder.proxy(proxy.clone());
     }
+    builder.build().map_err(Error::Http)
+}
+
+/// Build a sensitive `Authorization: Bearer` header value.
+fn bearer_header(token: &str) -> Result<reqwest::header::HeaderValue, Error> {
+    let mut value = reqwest::header::HeaderValue::from_str(&format!("Bearer {}", token))
+        .map_err(|_| Error::InvalidToken)?;
+    value.set_sensitive(true);
+    Ok(value)
+}
+
+/// Name of the environment variable holding `suffix` for a named account.
+pub(crate) fn account_env_var(name: &str, suffix: &str) -> String {
+    format!(
+        "ASANA_{}_{}",
+        name.trim().to_uppercase().replace('-', "_"),
+        suffix
+    )
+}
+
+/// Pick the token from `ASANA_TOKEN`, falling back to the file named by `ASANA_TOKEN_FILE`.
+fn resolve_env_token(token: Option<String>, token_file: Option<String>) -> Result<String, Error> {
+    match (token, token_file) {
+        (Some(token), _) if !token.is_empty() => Ok(token),
+        (_, Some(path)) if !path.is_empty() => read_token_file(Path::new(&path)),
+        _ => Err(Error::MissingToken),
+    }
+}
+
+/// Read a token from `path`, trimming trailing whitespace.
+fn read_token_file(path: &Path) -> Result<String, Error> {
+    let contents = std::fs::read_to_string(path).map_err(|e| {
+        Error::Config(format!(
+            "failed to read token file {}: {}",
+            path.display(),
+            e
+        ))
+    })?;
+
+    let token = contents.trim_end();
+    if token.is_empty() {
+        return Err(Error::MissingToken);
+    }
+    Ok(token.to_string())
+}
+
+/// Parse a timeout in whole seconds, as given in `ASANA_TIMEOUT_SECS`.
+fn parse_timeout_secs(value: &str) -> Result<Duration, Error> {
+    match value.trim().parse::<u64>() {
+        Ok(secs) if secs > 0 => Ok(Duration::from_secs(secs)),
+        _ => Err(Error::Config(format!(
+            "{} must be a positive number of seconds, got {:?}",
+            TIMEOUT_ENV_VAR, value
+        ))),
+    }
+}
+
+/// Validate a base URL, as given in `ASANA_BASE_URL`, and strip any trailing slash.
+fn parse_base_url(value: &str) -> Result<String, Error> {
+    let value = value.trim();
+    match reqwest::Url::parse(value) {
+        Ok(url) if matches!(url.scheme(), "http" | "https") && url.has_host() => {
+            Ok(value.trim_end_matches('/').to_string())
+        }
+        _ => Err(Error::Config(format!(
+            "{} must be an http(s) URL, got {:?}",
+            BASE_URL_ENV_VAR, value
+        ))),
+    }
+}
+
+/// A pseudo-random delay in `[0, max)` to spread out concurrent retries.
+fn jitter(max: Duration) -> Duration {
+    let nanos = max.as_nanos() as u64;
+    if nanos == 0 {
+        return Duration::ZERO;
+    }
+    let seed = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.subsec_nanos() as u64)
+        .unwrap_or(0);
+    Duration::from_nanos(seed % nanos)
+}
+
+/// Parse the `Retry-After` header (in seconds) from a rate-limited response.
+fn parse_retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
 
+/// The first entry of an Asana API error response's `errors` array.
+#[derive(Debug, PartialEq, serde::Deserialize)]
+struct ApiErrorDetail {
+    message: String,
+    /// Guidance on fixing the request, included on most 400 responses.
+    #[serde(default)]
+    help: Option<String>,
+    /// A unique phrase identifying the error, included on 500 responses.
+    #[serde(default)]
+    phrase: Option<String>,
+}
+
+/// Extract the error details from an Asana API error response.
+fn extract_error(body: &str) -> Option<ApiErrorDetail> {
     #[derive(serde::Deserialize)]
-    struct ErrorDetail {
-        message: String,
+    struct ErrorResponse {
+        errors: Vec<ApiErrorDetail>,
     }
 
     serde_json::from_str::<ErrorResponse>(body)
         .ok()
         .and_then(|r| r.errors.into_iter().next())
-        .map(|e| e.message)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use serde::{Deserialize, Serialize};
-    use wiremock::matchers::{method, path, query_param};
+    use wiremock::matchers::{body_json, body_string_contains, header, method, path, query_param};
     use wiremock::{Match, Mock, MockServer, Request, ResponseTemplate};
 
     /// Custom matcher that matches requests without an "offset" query parameter.
@@ -301,6 +1369,213 @@ mod tests {
         assert_eq!(client.base_url(), BASE_URL);
     }
 
+    #[test]
+    fn test_new_client_uses_default_timeout() {
+        let client = AsanaClient::new("test-token").unwrap();
+        assert_eq!(client.timeout(), DEFAULT_TIMEOUT);
+
+        let client = client.with_timeout(Duration::from_secs(5));
+        assert_eq!(client.timeout(), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_with_connect_timeout() {
+        let client = AsanaClient::new("test-token")
+            .unwrap()
+            .with_connect_timeout(Duration::from_secs(2));
+        assert!(client.is_ok());
+    }
+
+    #[cfg(feature = "compression")]
+    #[tokio::test]
+    async fn test_gzip_response_is_decoded() {
+        let server = MockServer::start().await;
+
+        // {"data":{"gid":"1","name":"Item"}} compressed with gzip
+        let body: &[u8] = &[
+            0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0xab, 0x56, 0x4a, 0x49,
+            0x2c, 0x49, 0x54, 0xb2, 0xaa, 0x56, 0x4a, 0xcf, 0x4c, 0x51, 0xb2, 0x52, 0x32, 0x54,
+            0xd2, 0x51, 0xca, 0x4b, 0xcc, 0x4d, 0x05, 0x32, 0x3d, 0x4b, 0x52, 0x73, 0x95, 0x6a,
+            0x6b, 0x01, 0x26, 0x5e, 0x2b, 0x0c, 0x22, 0x00, 0x00, 0x00,
+        ];
+        Mock::given(method("GET"))
+            .and(path("/items/1"))
+            .and(|request: &Request| {
+                request
+                    .headers
+                    .get("accept-encoding")
+                    .and_then(|value| value.to_str().ok())
+                    .is_some_and(|value| value.contains("gzip"))
+            })
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-encoding", "gzip")
+                    .insert_header("content-type", "application/json")
+                    .set_body_bytes(body),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let item: TestItem = test_client(&server).get("/items/1", &[]).await.unwrap();
+        assert_eq!(item.name, "Item");
+    }
+
+    #[tokio::test]
+    async fn test_connection_settings_survive_rebuilds() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/items/1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {"gid": "1", "name": "Item"}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server)
+            .with_pool_max_idle_per_host(4)
+            .unwrap()
+            .with_connect_timeout(Duration::from_secs(2))
+            .unwrap();
+        assert_eq!(client.http_config.pool_max_idle_per_host, Some(4));
+        assert_eq!(client.http_config.connect_timeout, Duration::from_secs(2));
+        let _: TestItem = client.get("/items/1", &[]).await.unwrap();
+
+        let client = client.with_http2_prior_knowledge(true).unwrap();
+        assert!(client.http_config.http2_prior_knowledge);
+        assert_eq!(client.http_config.pool_max_idle_per_host, Some(4));
+    }
+
+    #[tokio::test]
+    async fn test_with_proxy() {
+        let proxy = MockServer::start().await;
+
+        // The proxy receives the request for the (unreachable) API host
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {"gid": "1", "name": "Item"}
+            })))
+            .expect(1)
+            .mount(&proxy)
+            .await;
+
+        let client = AsanaClient::new("test-token")
+            .unwrap()
+            .with_base_url("http://asana.invalid")
+            .with_proxy(&proxy.uri())
+            .unwrap();
+        let item: TestItem = client.get("/items/1", &[]).await.unwrap();
+        assert_eq!(item.name, "Item");
+
+        assert!(matches!(
+            AsanaClient::new("test-token")
+                .unwrap()
+                .with_proxy("not a url"),
+            Err(Error::Config(_))
+        ));
+    }
+
+    #[test]
+    fn test_debug_output_hides_token() {
+        let client = AsanaClient::new("super-secret-token").unwrap();
+        assert!(!format!("{:?}", client).contains("super-secret-token"));
+    }
+
+    #[test]
+    fn test_parse_timeout_secs() {
+        assert_eq!(parse_timeout_secs("45").unwrap(), Duration::from_secs(45));
+        assert_eq!(parse_timeout_secs(" 5 ").unwrap(), Duration::from_secs(5));
+        assert!(matches!(parse_timeout_secs("0"), Err(Error::Config(_))));
+        assert!(matches!(parse_timeout_secs("abc"), Err(Error::Config(_))));
+    }
+
+    fn write_token_file(name: &str, contents: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("asanamcp-{}-{}", name, std::process::id()));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_read_token_file_trims_trailing_newline() {
+        let path = write_token_file("token-trim", "secret-token\n\n");
+        assert_eq!(read_token_file(&path).unwrap(), "secret-token");
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_read_token_file_errors() {
+        let path = write_token_file("token-empty", "\n");
+        assert!(matches!(read_token_file(&path), Err(Error::MissingToken)));
+        std::fs::remove_file(&path).unwrap();
+
+        let err = read_token_file(&path).unwrap_err();
+        assert!(matches!(err, Error::Config(_)));
+        assert!(err.to_string().contains("asanamcp-token-empty"));
+    }
+
+    #[test]
+    fn test_from_token_file() {
+        let path = write_token_file("token-client", "secret-token\n");
+        assert!(AsanaClient::from_token_file(&path).is_ok());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_account_env_var() {
+        assert_eq!(account_env_var("acme", "TOKEN"), "ASANA_ACME_TOKEN");
+        assert_eq!(
+            account_env_var("acme-corp", "DEFAULT_WORKSPACE"),
+            "ASANA_ACME_CORP_DEFAULT_WORKSPACE"
+        );
+    }
+
+    #[test]
+    fn test_resolve_env_token_precedence() {
+        let path = write_token_file("token-precedence", "file-token\n");
+        let file = Some(path.to_string_lossy().into_owned());
+
+        assert_eq!(
+            resolve_env_token(Some("env-token".into()), file.clone()).unwrap(),
+            "env-token"
+        );
+        assert_eq!(
+            resolve_env_token(Some(String::new()), file.clone()).unwrap(),
+            "file-token"
+        );
+        assert_eq!(resolve_env_token(None, file).unwrap(), "file-token");
+        assert!(matches!(
+            resolve_env_token(None, None),
+            Err(Error::MissingToken)
+        ));
+        assert!(matches!(
+            resolve_env_token(Some(String::new()), Some(String::new())),
+            Err(Error::MissingToken)
+        ));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_parse_base_url() {
+        assert_eq!(
+            parse_base_url("https://proxy.example.com/api/1.0/").unwrap(),
+            "https://proxy.example.com/api/1.0"
+        );
+        assert_eq!(
+            parse_base_url("http://localhost:8080").unwrap(),
+            "http://localhost:8080"
+        );
+        assert!(matches!(
+            parse_base_url("proxy.example.com"),
+            Err(Error::Config(_))
+        ));
+        assert!(matches!(
+            parse_base_url("ftp://proxy.example.com"),
+            Err(Error::Config(_))
+        ));
+    }
+
     #[test]
     fn test_empty_token_creates_client() {
         // Empty token creates a valid client; the API will reject it at request time
@@ -447,7 +1722,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_get_api_error_extracts_message() {
+    async fn test_get_forbidden_error_extracts_message() {
         let server = MockServer::start().await;
 
         Mock::given(method("GET"))
@@ -462,88 +1737,383 @@ mod tests {
         let result: Result<TestItem, Error> = client.get("/items/forbidden", &[]).await;
 
         match result {
-            Err(Error::Api { message }) => assert_eq!(message, "Not authorized"),
-            _ => panic!("Expected Api error"),
+            Err(Error::Forbidden(message)) => assert_eq!(message, "Not authorized"),
+            _ => panic!("Expected Forbidden error"),
         }
     }
 
     #[tokio::test]
-    async fn test_get_api_error_fallback_message() {
+    async fn test_get_unauthorized_error() {
         let server = MockServer::start().await;
 
         Mock::given(method("GET"))
-            .and(path("/items/error"))
-            .respond_with(ResponseTemplate::new(500).set_body_string("Internal Server Error"))
+            .and(path("/items/1"))
+            .respond_with(ResponseTemplate::new(401).set_body_json(serde_json::json!({
+                "errors": [{"message": "Not Authorized"}]
+            })))
             .mount(&server)
             .await;
 
         let client = test_client(&server);
-        let result: Result<TestItem, Error> = client.get("/items/error", &[]).await;
+        let result: Result<TestItem, Error> = client.get("/items/1", &[]).await;
 
         match result {
-            Err(Error::Api { message }) => assert!(message.contains("500")),
-            _ => panic!("Expected Api error"),
+            Err(Error::Unauthorized(message)) => assert_eq!(message, "Not Authorized"),
+            _ => panic!("Expected Unauthorized error, got {:?}", result),
         }
     }
 
-    // ========== get_all() pagination tests ==========
-
     #[tokio::test]
-    async fn test_get_all_single_page() {
+    async fn test_default_client_identification_headers() {
         let server = MockServer::start().await;
 
         Mock::given(method("GET"))
-            .and(path("/items"))
-            .and(NoOffset)
+            .and(path("/items/1"))
+            .and(header("user-agent", DEFAULT_USER_AGENT))
+            .and(header("x-asana-client-lib", CLIENT_LIB))
             .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-                "data": [
-                    {"gid": "1", "name": "Item 1"},
-                    {"gid": "2", "name": "Item 2"}
-                ],
-                "next_page": null
+                "data": {"gid": "1", "name": "Item"}
             })))
+            .expect(1)
             .mount(&server)
             .await;
 
         let client = test_client(&server);
-        let items: Vec<TestItem> = client.get_all("/items", &[]).await.unwrap();
-
-        assert_eq!(items.len(), 2);
-        assert_eq!(items[0].gid, "1");
-        assert_eq!(items[1].gid, "2");
+        let _: TestItem = client.get("/items/1", &[]).await.unwrap();
     }
 
     #[tokio::test]
-    async fn test_get_all_multiple_pages() {
+    async fn test_with_user_agent() {
         let server = MockServer::start().await;
 
-        // First page (no offset param)
-        Mock::given(method("GET"))
+        Mock::given(method("POST"))
             .and(path("/items"))
-            .and(NoOffset)
-            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-                "data": [{"gid": "1", "name": "Item 1"}],
-                "next_page": {"offset": "page2"}
+            .and(header("user-agent", "my-integration/2.0"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "data": {"gid": "1", "name": "Item"}
             })))
+            .expect(1)
             .mount(&server)
             .await;
 
-        // Second page
+        let client = test_client(&server)
+            .with_user_agent("my-integration/2.0")
+            .unwrap();
+        assert_eq!(client.user_agent(), "my-integration/2.0");
+        let _: TestItem = client
+            .post("/items", &serde_json::json!({"data": {}}))
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            test_client(&server).with_user_agent("bad\nagent"),
+            Err(Error::Config(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_with_opt_expand() {
+        let server = MockServer::start().await;
+
         Mock::given(method("GET"))
-            .and(path("/items"))
-            .and(query_param("offset", "page2"))
+            .and(path("/items/1"))
+            .and(query_param("opt_expand", "assignee,projects"))
+            .and(query_param("opt_pretty", "true"))
             .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-                "data": [{"gid": "2", "name": "Item 2"}],
-                "next_page": {"offset": "page3"}
+                "data": {"gid": "1", "name": "Item"}
             })))
+            .expect(1)
             .mount(&server)
             .await;
 
-        // Third (final) page
-        Mock::given(method("GET"))
-            .and(path("/items"))
-            .and(query_param("offset", "page3"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        let client = test_client(&server)
+            .with_opt_expand(&["assignee"])
+            .with_opt_expand(&["assignee", "projects"])
+            .with_opt_pretty(true);
+        let _: TestItem = client.get("/items/1", &[]).await.unwrap();
+    }
+
+    // ========== OAuth tests ==========
+
+    fn oauth_client(server: &MockServer) -> AsanaClient {
+        AsanaClient::with_oauth("old-token", "refresh-token", "client-id", "client-secret")
+            .unwrap()
+            .with_base_url(&server.uri())
+            .with_oauth_token_url(&format!("{}/-/oauth_token", server.uri()))
+    }
+
+    async fn mount_oauth_items(server: &MockServer) {
+        Mock::given(method("GET"))
+            .and(path("/items/1"))
+            .and(header("authorization", "Bearer old-token"))
+            .respond_with(ResponseTemplate::new(401).set_body_json(serde_json::json!({
+                "errors": [{"message": "Not Authorized"}]
+            })))
+            .mount(server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/items/1"))
+            .and(header("authorization", "Bearer new-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {"gid": "1", "name": "Item"}
+            })))
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_oauth_refreshes_on_401_and_retries() {
+        let server = MockServer::start().await;
+        mount_oauth_items(&server).await;
+
+        Mock::given(method("POST"))
+            .and(path("/-/oauth_token"))
+            .and(body_string_contains("grant_type=refresh_token"))
+            .and(body_string_contains("refresh_token=refresh-token"))
+            .and(body_string_contains("client_id=client-id"))
+            .and(body_string_contains("client_secret=client-secret"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "new-token",
+                "token_type": "bearer",
+                "expires_in": 3600
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = oauth_client(&server);
+        let item: TestItem = client.get("/items/1", &[]).await.unwrap();
+        assert_eq!(item.name, "Item");
+
+        // The refreshed token is reused, so later requests don't refresh again
+        let item: TestItem = client.clone().get("/items/1", &[]).await.unwrap();
+        assert_eq!(item.gid, "1");
+    }
+
+    #[tokio::test]
+    async fn test_oauth_concurrent_401s_refresh_once() {
+        let server = MockServer::start().await;
+        mount_oauth_items(&server).await;
+
+        Mock::given(method("POST"))
+            .and(path("/-/oauth_token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "new-token"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = oauth_client(&server);
+        let (a, b) = tokio::join!(
+            client.get::<TestItem>("/items/1", &[]),
+            client.get::<TestItem>("/items/1", &[])
+        );
+        assert!(a.is_ok());
+        assert!(b.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_oauth_refresh_failure() {
+        let server = MockServer::start().await;
+        mount_oauth_items(&server).await;
+
+        Mock::given(method("POST"))
+            .and(path("/-/oauth_token"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "error": "invalid_grant",
+                "error_description": "The refresh token is invalid"
+            })))
+            .mount(&server)
+            .await;
+
+        let client = oauth_client(&server);
+        let result: Result<TestItem, Error> = client.get("/items/1", &[]).await;
+
+        match result {
+            Err(Error::Unauthorized(message)) => {
+                assert!(message.contains("The refresh token is invalid"))
+            }
+            _ => panic!("Expected Unauthorized error, got {:?}", result),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_oauth_retries_only_once() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/items/1"))
+            .respond_with(ResponseTemplate::new(401).set_body_json(serde_json::json!({
+                "errors": [{"message": "Not Authorized"}]
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/-/oauth_token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "new-token"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = oauth_client(&server);
+        let result: Result<TestItem, Error> = client.get("/items/1", &[]).await;
+        assert!(matches!(result, Err(Error::Unauthorized(_))));
+    }
+
+    #[test]
+    fn test_oauth_debug_hides_secrets() {
+        let client = AsanaClient::with_oauth("access", "refresh", "client-id", "shh").unwrap();
+        let debug = format!("{:?}", client);
+        assert!(debug.contains("client-id"));
+        assert!(!debug.contains("shh"));
+        assert!(!debug.contains("refresh\""));
+    }
+
+    #[tokio::test]
+    async fn test_get_validation_error_includes_help() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/items/1"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "errors": [{
+                    "message": "opt_fields: Unknown field: bogus",
+                    "help": "See the API reference for valid fields"
+                }]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let result: Result<TestItem, Error> = client.get("/items/1", &[]).await;
+
+        match result {
+            Err(
+                ref error @ Error::Validation {
+                    ref message,
+                    ref help,
+                },
+            ) => {
+                assert_eq!(error.api_message(), Some(message.as_str()));
+                assert_eq!(message, "opt_fields: Unknown field: bogus");
+                assert_eq!(
+                    help.as_deref(),
+                    Some("See the API reference for valid fields")
+                );
+            }
+            other => panic!("Expected Validation error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_server_error_includes_phrase() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/items/1"))
+            .respond_with(ResponseTemplate::new(500).set_body_json(serde_json::json!({
+                "errors": [{"message": "Server Error", "phrase": "6 sad squid snuggle softly"}]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let result: Result<TestItem, Error> = client.get("/items/1", &[]).await;
+
+        match result {
+            Err(Error::Api { message }) => {
+                assert_eq!(
+                    message,
+                    "Server Error (error phrase: 6 sad squid snuggle softly)"
+                )
+            }
+            other => panic!("Expected Api error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_api_error_fallback_message() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/items/error"))
+            .respond_with(ResponseTemplate::new(500).set_body_string("Internal Server Error"))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let result: Result<TestItem, Error> = client.get("/items/error", &[]).await;
+
+        match result {
+            Err(Error::Api { message }) => assert!(message.contains("500")),
+            _ => panic!("Expected Api error"),
+        }
+    }
+
+    // ========== get_all() pagination tests ==========
+
+    #[tokio::test]
+    async fn test_get_all_single_page() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .and(NoOffset)
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    {"gid": "1", "name": "Item 1"},
+                    {"gid": "2", "name": "Item 2"}
+                ],
+                "next_page": null
+            })))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let items: Vec<TestItem> = client.get_all("/items", &[]).await.unwrap();
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].gid, "1");
+        assert_eq!(items[1].gid, "2");
+    }
+
+    #[tokio::test]
+    async fn test_get_all_multiple_pages() {
+        let server = MockServer::start().await;
+
+        // First page (no offset param)
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .and(NoOffset)
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [{"gid": "1", "name": "Item 1"}],
+                "next_page": {"offset": "page2"}
+            })))
+            .mount(&server)
+            .await;
+
+        // Second page
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .and(query_param("offset", "page2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [{"gid": "2", "name": "Item 2"}],
+                "next_page": {"offset": "page3"}
+            })))
+            .mount(&server)
+            .await;
+
+        // Third (final) page
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .and(query_param("offset", "page3"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
                 "data": [{"gid": "3", "name": "Item 3"}],
                 "next_page": null
             })))
@@ -553,326 +2123,1211 @@ mod tests {
         let client = test_client(&server);
         let items: Vec<TestItem> = client.get_all("/items", &[]).await.unwrap();
 
-        assert_eq!(items.len(), 3);
-        assert_eq!(items[0].gid, "1");
-        assert_eq!(items[1].gid, "2");
-        assert_eq!(items[2].gid, "3");
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0].gid, "1");
+        assert_eq!(items[1].gid, "2");
+        assert_eq!(items[2].gid, "3");
+    }
+
+    #[tokio::test]
+    async fn test_get_all_empty_result() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .and(NoOffset)
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [],
+                "next_page": null
+            })))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let items: Vec<TestItem> = client.get_all("/items", &[]).await.unwrap();
+
+        assert!(items.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_all_preserves_query_params() {
+        let server = MockServer::start().await;
+
+        // First page with query param (no offset)
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .and(query_param("workspace", "123"))
+            .and(NoOffset)
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [{"gid": "1", "name": "Item 1"}],
+                "next_page": {"offset": "page2"}
+            })))
+            .mount(&server)
+            .await;
+
+        // Second page should also have workspace param
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .and(query_param("workspace", "123"))
+            .and(query_param("offset", "page2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [{"gid": "2", "name": "Item 2"}],
+                "next_page": null
+            })))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let items: Vec<TestItem> = client
+            .get_all("/items", &[("workspace", "123")])
+            .await
+            .unwrap();
+
+        assert_eq!(items.len(), 2);
+    }
+
+    /// Matches requests whose query contains `key` exactly once, with the given value.
+    struct SingleQueryParam(&'static str, &'static str);
+
+    impl Match for SingleQueryParam {
+        fn matches(&self, request: &Request) -> bool {
+            let values: Vec<_> = request
+                .url
+                .query_pairs()
+                .filter(|(k, _)| k == self.0)
+                .map(|(_, v)| v.into_owned())
+                .collect();
+            values == [self.1]
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_all_requests_full_pages() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .and(NoOffset)
+            .and(SingleQueryParam("limit", "100"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [{"gid": "1", "name": "Item 1"}],
+                "next_page": {"offset": "page2"}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .and(query_param("offset", "page2"))
+            .and(SingleQueryParam("limit", "100"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [{"gid": "2", "name": "Item 2"}],
+                "next_page": null
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let items: Vec<TestItem> = client.get_all("/items", &[]).await.unwrap();
+
+        assert_eq!(items.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_all_respects_caller_page_size() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .and(SingleQueryParam("limit", "10"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [{"gid": "1", "name": "Item 1"}],
+                "next_page": null
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let items: Vec<TestItem> = client.get_all("/items", &[("limit", "10")]).await.unwrap();
+
+        assert_eq!(items.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_all_limited_requests_small_pages_for_small_limits() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .and(SingleQueryParam("limit", "5"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [{"gid": "1", "name": "Item 1"}],
+                "next_page": null
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let items: LimitedList<TestItem> = client
+            .get_all_limited("/items", &[], Some(5))
+            .await
+            .unwrap();
+
+        assert_eq!(items.data.len(), 1);
+    }
+
+    // ========== get_all_limited() tests ==========
+
+    #[tokio::test]
+    async fn test_get_all_limited_stops_fetching_at_limit() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .and(NoOffset)
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    {"gid": "1", "name": "Item 1"},
+                    {"gid": "2", "name": "Item 2"}
+                ],
+                "next_page": {"offset": "page2"}
+            })))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .and(query_param("offset", "page2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    {"gid": "3", "name": "Item 3"},
+                    {"gid": "4", "name": "Item 4"}
+                ],
+                "next_page": {"offset": "page3"}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .and(query_param("offset", "page3"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let items: LimitedList<TestItem> = client
+            .get_all_limited("/items", &[], Some(3))
+            .await
+            .unwrap();
+
+        assert_eq!(items.data.len(), 3);
+        assert_eq!(items.data[2].gid, "3");
+        assert!(items.has_more);
+    }
+
+    #[tokio::test]
+    async fn test_get_all_limited_not_truncated_when_results_fit() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    {"gid": "1", "name": "Item 1"},
+                    {"gid": "2", "name": "Item 2"}
+                ],
+                "next_page": null
+            })))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let exact: LimitedList<TestItem> = client
+            .get_all_limited("/items", &[], Some(2))
+            .await
+            .unwrap();
+        let unlimited: LimitedList<TestItem> =
+            client.get_all_limited("/items", &[], None).await.unwrap();
+
+        assert_eq!(exact.data.len(), 2);
+        assert!(!exact.has_more);
+        assert_eq!(unlimited.data.len(), 2);
+        assert!(!unlimited.has_more);
+    }
+
+    // ========== stream_all() tests ==========
+
+    #[tokio::test]
+    async fn test_stream_all_yields_items_across_pages() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .and(NoOffset)
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    {"gid": "1", "name": "Item 1"},
+                    {"gid": "2", "name": "Item 2"}
+                ],
+                "next_page": {"offset": "page2"}
+            })))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .and(query_param("offset", "page2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [{"gid": "3", "name": "Item 3"}],
+                "next_page": null
+            })))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let items: Vec<TestItem> = client
+            .stream_all("/items", &[])
+            .try_collect()
+            .await
+            .unwrap();
+
+        let gids: Vec<&str> = items.iter().map(|i| i.gid.as_str()).collect();
+        assert_eq!(gids, vec!["1", "2", "3"]);
+    }
+
+    #[tokio::test]
+    async fn test_stream_all_fetches_pages_lazily() {
+        use futures::StreamExt;
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .and(NoOffset)
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    {"gid": "1", "name": "Item 1"},
+                    {"gid": "2", "name": "Item 2"}
+                ],
+                "next_page": {"offset": "page2"}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .and(query_param("offset", "page2"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let items: Vec<TestItem> = client
+            .stream_all("/items", &[])
+            .take(2)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(items.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_stream_all_surfaces_page_errors() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .and(NoOffset)
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [{"gid": "1", "name": "Item 1"}],
+                "next_page": {"offset": "page2"}
+            })))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .and(query_param("offset", "page2"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "errors": [{"message": "Not found"}]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let mut items = pin!(client.stream_all::<TestItem>("/items", &[]));
+
+        assert_eq!(items.try_next().await.unwrap().unwrap().gid, "1");
+        assert!(matches!(items.try_next().await, Err(Error::NotFound(_))));
+    }
+
+    // ========== post() tests ==========
+
+    #[tokio::test]
+    async fn test_post_success() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/items"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "data": {"gid": "new123", "name": "Created Item"}
+            })))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+
+        #[derive(Serialize)]
+        struct CreateRequest {
+            data: CreateData,
+        }
+        #[derive(Serialize)]
+        struct CreateData {
+            name: String,
+        }
+
+        let body = CreateRequest {
+            data: CreateData {
+                name: "Created Item".to_string(),
+            },
+        };
+
+        let item: TestItem = client.post("/items", &body).await.unwrap();
+
+        assert_eq!(item.gid, "new123");
+        assert_eq!(item.name, "Created Item");
+    }
+
+    #[tokio::test]
+    async fn test_post_api_error() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/items"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "errors": [{"message": "Invalid request data"}]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let body = serde_json::json!({"data": {}});
+
+        let result: Result<TestItem, Error> = client.post("/items", &body).await;
+
+        match result {
+            Err(Error::Validation { message, .. }) => assert_eq!(message, "Invalid request data"),
+            _ => panic!("Expected Validation error"),
+        }
+    }
+
+    // ========== post_multipart() tests ==========
+
+    #[tokio::test]
+    async fn test_post_multipart_success() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/attachments"))
+            .and(body_string_contains(
+                "Content-Disposition: form-data; name=\"parent\"\r\n\r\ntask123\r\n",
+            ))
+            .and(body_string_contains(
+                "Content-Disposition: form-data; name=\"name\"\r\n\r\nSpec \"v2\"\r\n",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {"gid": "att123", "name": "Spec"}
+            })))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let item: TestItem = client
+            .post_multipart(
+                "/attachments",
+                &[("parent", "task123"), ("name", "Spec \"v2\"")],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(item.gid, "att123");
+    }
+
+    // ========== batch() tests ==========
+
+    #[tokio::test]
+    async fn test_batch_sends_envelope_and_parses_results() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/batch"))
+            .and(body_json(serde_json::json!({
+                "data": {
+                    "actions": [
+                        {"method": "get", "relative_path": "/tasks/1"},
+                        {"method": "put", "relative_path": "/tasks/2", "data": {"completed": true}}
+                    ]
+                }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    {"status_code": 200, "headers": {}, "body": {"data": {"gid": "1"}}},
+                    {"status_code": 404, "headers": {}, "body": {"errors": [{"message": "Not found"}]}}
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let actions = vec![
+            serde_json::json!({"method": "get", "relative_path": "/tasks/1"}),
+            serde_json::json!({"method": "put", "relative_path": "/tasks/2", "data": {"completed": true}}),
+        ];
+        let results = client.batch(&actions).await.unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].status_code, 200);
+        assert_eq!(results[0].body["data"]["gid"], "1");
+        assert_eq!(results[1].status_code, 404);
+    }
+
+    // ========== get_events() tests ==========
+
+    #[tokio::test]
+    async fn test_get_events_returns_events_and_sync_token() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/events"))
+            .and(query_param("resource", "proj1"))
+            .and(query_param("sync", "old-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    {"action": "changed", "resource": {"gid": "task1", "resource_type": "task"}}
+                ],
+                "sync": "new-token",
+                "has_more": false
+            })))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let page = client.get_events("proj1", Some("old-token")).await.unwrap();
+
+        assert_eq!(page.data.len(), 1);
+        assert_eq!(page.data[0]["action"], "changed");
+        assert_eq!(page.sync, "new-token");
+        assert!(!page.has_more);
+    }
+
+    #[tokio::test]
+    async fn test_get_events_expired_token_returns_fresh_token() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/events"))
+            .and(query_param("resource", "proj1"))
+            .respond_with(ResponseTemplate::new(412).set_body_json(serde_json::json!({
+                "errors": [{"message": "Sync token invalid or too old."}],
+                "sync": "fresh-token"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let page = client.get_events("proj1", None).await.unwrap();
+
+        assert!(page.data.is_empty());
+        assert_eq!(page.sync, "fresh-token");
+    }
+
+    // ========== put() tests ==========
+
+    #[tokio::test]
+    async fn test_put_success() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("PUT"))
+            .and(path("/items/123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {"gid": "123", "name": "Updated Item"}
+            })))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let body = serde_json::json!({"data": {"name": "Updated Item"}});
+
+        let item: TestItem = client.put("/items/123", &body).await.unwrap();
+
+        assert_eq!(item.gid, "123");
+        assert_eq!(item.name, "Updated Item");
+    }
+
+    #[tokio::test]
+    async fn test_put_not_found() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("PUT"))
+            .and(path("/items/missing"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let body = serde_json::json!({"data": {}});
+
+        let result: Result<TestItem, Error> = client.put("/items/missing", &body).await;
+
+        assert!(matches!(result, Err(Error::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_records_writes_and_sends_reads() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/items/1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {"gid": "1", "name": "Item"}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server).with_dry_run();
+        let item: TestItem = client.get("/items/1", &[]).await.unwrap();
+        assert_eq!(item.name, "Item");
+
+        let body = serde_json::json!({"data": {"name": "New"}});
+        let result: Result<TestItem, Error> = client.put("/items/1", &body).await;
+        assert!(matches!(result, Err(Error::DryRun { method: "PUT", .. })));
+        assert!(client.delete("/items/1").await.is_err());
+
+        assert_eq!(
+            client.planned_requests(),
+            vec![
+                PlannedRequest {
+                    method: "PUT",
+                    path: "/items/1".to_string(),
+                    body: Some(body),
+                },
+                PlannedRequest {
+                    method: "DELETE",
+                    path: "/items/1".to_string(),
+                    body: None,
+                },
+            ]
+        );
+    }
+
+    // ========== post_empty() tests ==========
+
+    #[tokio::test]
+    async fn test_post_empty_success() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/tasks/123/addProject"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {}
+            })))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let body = serde_json::json!({"data": {"project": "proj456"}});
+
+        let result = client.post_empty("/tasks/123/addProject", &body).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_post_empty_accepts_no_content() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/tasks/123/addTag"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let body = serde_json::json!({"data": {"tag": "tag456"}});
+
+        assert!(client.post_empty("/tasks/123/addTag", &body).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_post_optional() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/tasks/1/addFollowers"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {"gid": "1", "name": "Item"}
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/tasks/2/addFollowers"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {}
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/tasks/3/addFollowers"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let body = serde_json::json!({"data": {"followers": ["u1"]}});
+
+        let item: Option<TestItem> = client
+            .post_optional("/tasks/1/addFollowers", &body)
+            .await
+            .unwrap();
+        assert_eq!(item.unwrap().name, "Item");
+
+        for task in ["2", "3"] {
+            let item: Option<TestItem> = client
+                .post_optional(&format!("/tasks/{}/addFollowers", task), &body)
+                .await
+                .unwrap();
+            assert!(item.is_none(), "task {}", task);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_post_empty_error() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/tasks/123/addProject"))
+            .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+                "errors": [{"message": "Not authorized to add to project"}]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let body = serde_json::json!({"data": {"project": "proj456"}});
+
+        let result = client.post_empty("/tasks/123/addProject", &body).await;
+
+        match result {
+            Err(Error::Forbidden(message)) => {
+                assert_eq!(message, "Not authorized to add to project")
+            }
+            _ => panic!("Expected Forbidden error"),
+        }
+    }
+
+    // ========== delete() tests ==========
+
+    #[tokio::test]
+    async fn test_delete_success() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/items/123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {}
+            })))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let result = client.delete("/items/123").await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_delete_not_found() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/items/missing"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let result = client.delete("/items/missing").await;
+
+        assert!(matches!(result, Err(Error::NotFound(_))));
+    }
+
+    // ========== delete_with_body() tests ==========
+
+    #[tokio::test]
+    async fn test_delete_with_body_success() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/tasks/123/removeDependencies"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {}
+            })))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let body = serde_json::json!({"data": {"dependencies": ["dep1", "dep2"]}});
+
+        let result = client
+            .delete_with_body("/tasks/123/removeDependencies", &body)
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_delete_with_body_error() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/tasks/123/removeDependencies"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "errors": [{"message": "Invalid dependencies"}]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let body = serde_json::json!({"data": {"dependencies": []}});
+
+        let result = client
+            .delete_with_body("/tasks/123/removeDependencies", &body)
+            .await;
+
+        match result {
+            Err(Error::Validation { message, .. }) => assert_eq!(message, "Invalid dependencies"),
+            _ => panic!("Expected Validation error"),
+        }
     }
 
+    // ========== timeout tests ==========
+
     #[tokio::test]
-    async fn test_get_all_empty_result() {
+    async fn test_request_times_out() {
         let server = MockServer::start().await;
 
         Mock::given(method("GET"))
-            .and(path("/items"))
-            .and(NoOffset)
-            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-                "data": [],
-                "next_page": null
-            })))
+            .and(path("/items/slow"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"data": {"gid": "1", "name": "Slow"}}))
+                    .set_delay(Duration::from_millis(500)),
+            )
             .mount(&server)
             .await;
 
-        let client = test_client(&server);
-        let items: Vec<TestItem> = client.get_all("/items", &[]).await.unwrap();
+        let client = test_client(&server).with_timeout(Duration::from_millis(50));
+        let result: Result<TestItem, Error> = client.get("/items/slow", &[]).await;
 
-        assert!(items.is_empty());
+        match result {
+            Err(Error::Timeout(e)) => assert!(e.is_timeout()),
+            other => panic!("Expected timeout error, got {:?}", other),
+        }
     }
 
     #[tokio::test]
-    async fn test_get_all_preserves_query_params() {
-        let server = MockServer::start().await;
+    async fn test_connection_error_is_not_a_timeout() {
+        // Bind then drop a listener so the port is very likely closed
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
 
-        // First page with query param (no offset)
-        Mock::given(method("GET"))
-            .and(path("/items"))
-            .and(query_param("workspace", "123"))
-            .and(NoOffset)
-            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-                "data": [{"gid": "1", "name": "Item 1"}],
-                "next_page": {"offset": "page2"}
-            })))
-            .mount(&server)
-            .await;
+        let client = AsanaClient::new("test-token")
+            .unwrap()
+            .with_base_url(&format!("http://{}", addr));
+        let result: Result<TestItem, Error> = client.get("/items/1", &[]).await;
+
+        match result {
+            Err(Error::Http(e)) => assert!(e.is_connect()),
+            other => panic!("Expected connection error, got {:?}", other),
+        }
+    }
+
+    // ========== rate limiting tests ==========
+
+    #[tokio::test]
+    async fn test_get_429_returns_rate_limited() {
+        let server = MockServer::start().await;
 
-        // Second page should also have workspace param
         Mock::given(method("GET"))
-            .and(path("/items"))
-            .and(query_param("workspace", "123"))
-            .and(query_param("offset", "page2"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-                "data": [{"gid": "2", "name": "Item 2"}],
-                "next_page": null
-            })))
+            .and(path("/items/123"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "30"))
             .mount(&server)
             .await;
 
         let client = test_client(&server);
-        let items: Vec<TestItem> = client
-            .get_all("/items", &[("workspace", "123")])
-            .await
-            .unwrap();
+        let result: Result<TestItem, Error> = client.get("/items/123", &[]).await;
 
-        assert_eq!(items.len(), 2);
+        match result {
+            Err(Error::RateLimited { retry_after }) => {
+                assert_eq!(retry_after, Some(Duration::from_secs(30)))
+            }
+            other => panic!("Expected RateLimited error, got {:?}", other),
+        }
     }
 
-    // ========== post() tests ==========
-
     #[tokio::test]
-    async fn test_post_success() {
+    async fn test_429_without_retry_after_header() {
         let server = MockServer::start().await;
 
-        Mock::given(method("POST"))
-            .and(path("/items"))
-            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
-                "data": {"gid": "new123", "name": "Created Item"}
-            })))
+        Mock::given(method("DELETE"))
+            .and(path("/items/123"))
+            .respond_with(ResponseTemplate::new(429))
             .mount(&server)
             .await;
 
         let client = test_client(&server);
+        let result = client.delete("/items/123").await;
 
-        #[derive(Serialize)]
-        struct CreateRequest {
-            data: CreateData,
-        }
-        #[derive(Serialize)]
-        struct CreateData {
-            name: String,
-        }
+        assert!(matches!(
+            result,
+            Err(Error::RateLimited { retry_after: None })
+        ));
+    }
 
-        let body = CreateRequest {
-            data: CreateData {
-                name: "Created Item".to_string(),
-            },
-        };
+    #[tokio::test]
+    async fn test_429_retries_once_when_enabled() {
+        let server = MockServer::start().await;
 
-        let item: TestItem = client.post("/items", &body).await.unwrap();
+        Mock::given(method("GET"))
+            .and(path("/items/123"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
 
-        assert_eq!(item.gid, "new123");
-        assert_eq!(item.name, "Created Item");
+        Mock::given(method("GET"))
+            .and(path("/items/123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {"gid": "123", "name": "After Retry"}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server).with_retry_on_rate_limit(true);
+        let item: TestItem = client.get("/items/123", &[]).await.unwrap();
+
+        assert_eq!(item.name, "After Retry");
     }
 
     #[tokio::test]
-    async fn test_post_api_error() {
+    async fn test_429_retry_gives_up_after_one_attempt() {
         let server = MockServer::start().await;
 
         Mock::given(method("POST"))
             .and(path("/items"))
-            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
-                "errors": [{"message": "Invalid request data"}]
-            })))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .expect(2)
             .mount(&server)
             .await;
 
-        let client = test_client(&server);
+        let client = test_client(&server).with_retry_on_rate_limit(true);
         let body = serde_json::json!({"data": {}});
-
         let result: Result<TestItem, Error> = client.post("/items", &body).await;
 
-        match result {
-            Err(Error::Api { message }) => assert_eq!(message, "Invalid request data"),
-            _ => panic!("Expected Api error"),
-        }
+        assert!(matches!(result, Err(Error::RateLimited { .. })));
     }
 
-    // ========== put() tests ==========
+    // ========== rate-limit header tests ==========
 
     #[tokio::test]
-    async fn test_put_success() {
+    async fn test_last_rate_limit_captured_from_response() {
         let server = MockServer::start().await;
 
-        Mock::given(method("PUT"))
+        Mock::given(method("GET"))
             .and(path("/items/123"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-                "data": {"gid": "123", "name": "Updated Item"}
-            })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"data": {"gid": "123", "name": "Item"}}))
+                    .insert_header("X-RateLimit-Limit", "1500")
+                    .insert_header("X-RateLimit-Remaining", "1499")
+                    .insert_header("X-RateLimit-Reset", "60"),
+            )
             .mount(&server)
             .await;
 
         let client = test_client(&server);
-        let body = serde_json::json!({"data": {"name": "Updated Item"}});
+        assert_eq!(client.last_rate_limit(), None);
 
-        let item: TestItem = client.put("/items/123", &body).await.unwrap();
+        let _: TestItem = client.get("/items/123", &[]).await.unwrap();
 
-        assert_eq!(item.gid, "123");
-        assert_eq!(item.name, "Updated Item");
+        assert_eq!(
+            client.last_rate_limit(),
+            Some(RateLimitInfo {
+                limit: Some(1500),
+                remaining: Some(1499),
+                reset: Some(60),
+            })
+        );
     }
 
     #[tokio::test]
-    async fn test_put_not_found() {
+    async fn test_last_rate_limit_updated_by_empty_and_error_responses() {
         let server = MockServer::start().await;
 
-        Mock::given(method("PUT"))
+        Mock::given(method("DELETE"))
+            .and(path("/items/123"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"data": {}}))
+                    .insert_header("X-RateLimit-Remaining", "10"),
+            )
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
             .and(path("/items/missing"))
-            .respond_with(ResponseTemplate::new(404))
+            .respond_with(ResponseTemplate::new(404).insert_header("X-RateLimit-Remaining", "9"))
             .mount(&server)
             .await;
 
         let client = test_client(&server);
-        let body = serde_json::json!({"data": {}});
 
-        let result: Result<TestItem, Error> = client.put("/items/missing", &body).await;
+        client.delete("/items/123").await.unwrap();
+        assert_eq!(client.last_rate_limit().unwrap().remaining, Some(10));
 
-        assert!(matches!(result, Err(Error::NotFound(_))));
-    }
+        let _ = client.get::<TestItem>("/items/missing", &[]).await;
+        assert_eq!(client.last_rate_limit().unwrap().remaining, Some(9));
 
-    // ========== post_empty() tests ==========
+        // Clones share the same rate-limit state
+        assert_eq!(client.clone().last_rate_limit().unwrap().remaining, Some(9));
+    }
 
     #[tokio::test]
-    async fn test_post_empty_success() {
+    async fn test_last_rate_limit_kept_when_headers_absent() {
         let server = MockServer::start().await;
 
-        Mock::given(method("POST"))
-            .and(path("/tasks/123/addProject"))
+        Mock::given(method("GET"))
+            .and(path("/items/1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"data": {"gid": "1", "name": "One"}}))
+                    .insert_header("X-RateLimit-Remaining", "5"),
+            )
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/items/2"))
             .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-                "data": {}
+                "data": {"gid": "2", "name": "Two"}
             })))
             .mount(&server)
             .await;
 
         let client = test_client(&server);
-        let body = serde_json::json!({"data": {"project": "proj456"}});
-
-        let result = client.post_empty("/tasks/123/addProject", &body).await;
+        let _: TestItem = client.get("/items/1", &[]).await.unwrap();
+        let _: TestItem = client.get("/items/2", &[]).await.unwrap();
 
-        assert!(result.is_ok());
+        assert_eq!(client.last_rate_limit().unwrap().remaining, Some(5));
     }
 
+    // ========== transient failure retry tests ==========
+
     #[tokio::test]
-    async fn test_post_empty_error() {
+    async fn test_get_retries_server_errors_until_success() {
         let server = MockServer::start().await;
 
-        Mock::given(method("POST"))
-            .and(path("/tasks/123/addProject"))
-            .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
-                "errors": [{"message": "Not authorized to add to project"}]
-            })))
+        Mock::given(method("GET"))
+            .and(path("/items/123"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .expect(2)
             .mount(&server)
             .await;
 
-        let client = test_client(&server);
-        let body = serde_json::json!({"data": {"project": "proj456"}});
+        Mock::given(method("GET"))
+            .and(path("/items/123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {"gid": "123", "name": "Recovered"}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
 
-        let result = client.post_empty("/tasks/123/addProject", &body).await;
+        let client = test_client(&server).with_retry(2, Duration::ZERO);
+        let item: TestItem = client.get("/items/123", &[]).await.unwrap();
 
-        match result {
-            Err(Error::Api { message }) => assert_eq!(message, "Not authorized to add to project"),
-            _ => panic!("Expected Api error"),
-        }
+        assert_eq!(item.name, "Recovered");
     }
 
-    // ========== delete() tests ==========
-
     #[tokio::test]
-    async fn test_delete_success() {
+    async fn test_get_gives_up_after_max_retries() {
         let server = MockServer::start().await;
 
-        Mock::given(method("DELETE"))
-            .and(path("/items/123"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-                "data": {}
-            })))
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(4)
             .mount(&server)
             .await;
 
-        let client = test_client(&server);
-        let result = client.delete("/items/123").await;
+        let client = test_client(&server).with_retry(3, Duration::ZERO);
+        let result: Result<Vec<TestItem>, Error> = client.get_all("/items", &[]).await;
 
-        assert!(result.is_ok());
+        match result {
+            Err(Error::Api { message }) => assert!(message.contains("500")),
+            other => panic!("Expected Api error, got {:?}", other),
+        }
     }
 
     #[tokio::test]
-    async fn test_delete_not_found() {
+    async fn test_get_does_not_retry_client_errors() {
         let server = MockServer::start().await;
 
-        Mock::given(method("DELETE"))
-            .and(path("/items/missing"))
-            .respond_with(ResponseTemplate::new(404))
+        Mock::given(method("GET"))
+            .and(path("/items/123"))
+            .respond_with(ResponseTemplate::new(400))
+            .expect(1)
             .mount(&server)
             .await;
 
-        let client = test_client(&server);
-        let result = client.delete("/items/missing").await;
+        let client = test_client(&server).with_retry(3, Duration::ZERO);
+        let result: Result<TestItem, Error> = client.get("/items/123", &[]).await;
 
-        assert!(matches!(result, Err(Error::NotFound(_))));
+        assert!(matches!(result, Err(Error::Validation { .. })));
     }
 
-    // ========== delete_with_body() tests ==========
-
     #[tokio::test]
-    async fn test_delete_with_body_success() {
+    async fn test_post_is_not_retried_on_server_error() {
         let server = MockServer::start().await;
 
-        Mock::given(method("DELETE"))
-            .and(path("/tasks/123/removeDependencies"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-                "data": {}
-            })))
+        Mock::given(method("POST"))
+            .and(path("/items"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(1)
             .mount(&server)
             .await;
 
-        let client = test_client(&server);
-        let body = serde_json::json!({"data": {"dependencies": ["dep1", "dep2"]}});
-
-        let result = client
-            .delete_with_body("/tasks/123/removeDependencies", &body)
-            .await;
+        let client = test_client(&server).with_retry(3, Duration::ZERO);
+        let body = serde_json::json!({"data": {"name": "New"}});
+        let result: Result<TestItem, Error> = client.post("/items", &body).await;
 
-        assert!(result.is_ok());
+        assert!(matches!(result, Err(Error::Api { .. })));
     }
 
     #[tokio::test]
-    async fn test_delete_with_body_error() {
+    async fn test_request_json_empty_body_is_null() {
         let server = MockServer::start().await;
 
         Mock::given(method("DELETE"))
-            .and(path("/tasks/123/removeDependencies"))
-            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
-                "errors": [{"message": "Invalid dependencies"}]
-            })))
+            .and(path("/items/1"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
             .mount(&server)
             .await;
 
         let client = test_client(&server);
-        let body = serde_json::json!({"data": {"dependencies": []}});
+        let value = client
+            .request_json("DELETE", "/items/1", &[], None)
+            .await
+            .unwrap();
 
-        let result = client
-            .delete_with_body("/tasks/123/removeDependencies", &body)
-            .await;
+        assert_eq!(value, serde_json::Value::Null);
+    }
 
-        match result {
-            Err(Error::Api { message }) => assert_eq!(message, "Invalid dependencies"),
-            _ => panic!("Expected Api error"),
-        }
+    #[test]
+    fn test_backoff_delay_grows_exponentially() {
+        let client = AsanaClient::new("test-token")
+            .unwrap()
+            .with_retry(5, Duration::from_millis(100));
+
+        let first = client.backoff_delay(0);
+        let third = client.backoff_delay(2);
+
+        assert!(first >= Duration::from_millis(100) && first < Duration::from_millis(200));
+        assert!(third >= Duration::from_millis(400) && third < Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        use reqwest::header::{HeaderMap, HeaderValue, RETRY_AFTER};
+
+        let mut headers = HeaderMap::new();
+        assert_eq!(parse_retry_after(&headers), None);
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("12"));
+        assert_eq!(parse_retry_after(&headers), Some(Duration::from_secs(12)));
+
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(parse_retry_after(&headers), None);
     }
 
-    // ========== extract_error_message tests ==========
+    // ========== extract_error tests ==========
 
     #[test]
-    fn test_extract_error_message_valid() {
+    fn test_extract_error_valid() {
         let body = r#"{"errors": [{"message": "Project not found"}]}"#;
         assert_eq!(
-            extract_error_message(body),
-            Some("Project not found".to_string())
+            extract_error(body),
+            Some(ApiErrorDetail {
+                message: "Project not found".to_string(),
+                help: None,
+                phrase: None,
+            })
         );
     }
 
     #[test]
-    fn test_extract_error_message_empty_errors() {
+    fn test_extract_error_with_help_and_phrase() {
+        let body = r#"{"errors": [{
+            "message": "custom_fields: Not a valid enum option",
+            "help": "For more information on API status codes see https://developers.asana.com",
+            "phrase": "6 sad squid snuggle softly"
+        }]}"#;
+        let detail = extract_error(body).unwrap();
+        assert_eq!(detail.message, "custom_fields: Not a valid enum option");
+        assert!(detail.help.unwrap().contains("developers.asana.com"));
+        assert_eq!(detail.phrase.as_deref(), Some("6 sad squid snuggle softly"));
+    }
+
+    #[test]
+    fn test_extract_error_empty_errors() {
         let body = r#"{"errors": []}"#;
-        assert_eq!(extract_error_message(body), None);
+        assert_eq!(extract_error(body), None);
     }
 
     #[test]
-    fn test_extract_error_message_malformed() {
+    fn test_extract_error_malformed() {
         let body = "not json";
-        assert_eq!(extract_error_message(body), None);
+        assert_eq!(extract_error(body), None);
     }
 
     #[test]
-    fn test_extract_error_message_wrong_structure() {
+    fn test_extract_error_wrong_structure() {
         let body = r#"{"error": "Something went wrong"}"#;
-        assert_eq!(extract_error_message(body), None);
+        assert_eq!(extract_error(body), None);
     }
 }
@@ -1,11 +1,13 @@
 //! Error types for the Asana MCP server.
 
+use std::time::Duration;
+
 use thiserror::Error;
 
 /// Errors that can occur when using the Asana client.
 #[derive(Debug, Error)]
 pub enum Error {
-    /// The `ASANA_TOKEN` environment variable is not set or is empty.
+    /// No token was found in `ASANA_TOKEN` or the file named by `ASANA_TOKEN_FILE`.
     #[error("ASANA_TOKEN environment variable is not set")]
     MissingToken,
 
@@ -13,9 +15,17 @@ pub enum Error {
     #[error("invalid token format")]
     InvalidToken,
 
-    /// An HTTP request failed.
+    /// A configuration value (e.g. an environment variable) is invalid.
+    #[error("invalid configuration: {0}")]
+    Config(String),
+
+    /// An HTTP request failed for a reason other than a timeout (e.g. DNS or connection errors).
     #[error("HTTP error: {0}")]
-    Http(#[from] reqwest::Error),
+    Http(#[source] reqwest::Error),
+
+    /// An HTTP request timed out.
+    #[error("request timed out: {0}")]
+    Timeout(#[source] reqwest::Error),
 
     /// Failed to parse a response from the API.
     #[error("failed to parse response: {0}")]
@@ -31,4 +41,63 @@ pub enum Error {
     /// A resource was not found.
     #[error("resource not found: {0}")]
     NotFound(String),
+
+    /// The API rejected the token with HTTP 401 (missing, invalid, or expired).
+    #[error("unauthorized: {0}")]
+    Unauthorized(String),
+
+    /// The API refused access with HTTP 403 (the token lacks permission or scope).
+    #[error("forbidden: {0}")]
+    Forbidden(String),
+
+    /// The API rejected the request with HTTP 400 (e.g. a malformed field value).
+    #[error("invalid request: {message}{}", help.as_ref().map(|h| format!(" ({})", h)).unwrap_or_default())]
+    Validation {
+        /// The error message from the API.
+        message: String,
+        /// Asana's guidance on fixing the request, when provided.
+        help: Option<String>,
+    },
+
+    /// A write was recorded instead of sent because the client is in dry-run mode.
+    #[error("dry run: {method} {path} was not sent")]
+    DryRun {
+        /// The HTTP method of the skipped request.
+        method: &'static str,
+        /// The API path of the skipped request.
+        path: String,
+    },
+
+    /// The API rejected the request with HTTP 429 (rate limit exceeded).
+    #[error("rate limited by Asana API{}", retry_after.map(|d| format!(" (retry after {}s)", d.as_secs())).unwrap_or_default())]
+    RateLimited {
+        /// How long Asana asked us to wait, from the `Retry-After` header.
+        retry_after: Option<Duration>,
+    },
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(error: reqwest::Error) -> Self {
+        if error.is_timeout() {
+            Self::Timeout(error)
+        } else {
+            Self::Http(error)
+        }
+    }
+}
+
+impl Error {
+    /// The message from an Asana error response, whichever status-specific variant holds it.
+    ///
+    /// Code that used to match `Error::Api { message }` for every failed request can call this
+    /// to also cover 400, 401, 403, and 404 responses, which now have their own variants.
+    pub fn api_message(&self) -> Option<&str> {
+        match self {
+            Self::Api { message } | Self::Validation { message, .. } => Some(message),
+            Self::NotFound(message) | Self::Unauthorized(message) | Self::Forbidden(message) => {
+                Some(message)
+            }
+            _ => None,
+        }
+    }
 }
@@ -30,7 +30,7 @@ pub mod server;
 pub mod types;
 
 // Re-export main types at crate root
-pub use client::AsanaClient;
+pub use client::{AsanaClient, RateLimitInfo};
 pub use error::Error;
 pub use server::AsanaServer;
 
@@ -39,6 +39,8 @@ pub use server::params;
 
 // Re-export commonly used types
 pub use types::{
-    FavoriteItem, FavoritesResponse, Job, PortfolioItem, PortfolioItemExpanded, PortfolioWithItems,
-    Resource, Story, TaskDependency, TaskRef, TaskWithContext,
+    BatchResult, EventsPage, FavoriteItem, FavoritesResponse, Job, LimitedList, PlannedRequest,
+    PortfolioItem, PortfolioItemError, PortfolioItemExpanded, PortfolioWithItems, Project,
+    Resource, Story, Task, TaskDependency, TaskRef, TaskSummary, TaskWithContext, TemplateVariable,
+    TemplateVariables, WhoAmI,
 };
@@ -12,7 +12,12 @@
 //! asanamcp
 //! ```
 //!
-//! The server communicates via STDIO using the MCP protocol.
+//! The server communicates via STDIO using the MCP protocol. Builds with the `http`
+//! feature can instead serve MCP over streamable HTTP/SSE:
+//!
+//! ```bash
+//! asanamcp --http 127.0.0.1:8080   # endpoint at http://127.0.0.1:8080/mcp
+//! ```
 //!
 //! # Schema Inspection
 //!
@@ -53,9 +58,22 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         return Ok(());
     }
 
+    // Handle --http flag
+    let http_addr = match args.iter().position(|a| a == "--http") {
+        Some(i) => match args.get(i + 1) {
+            Some(addr) if !addr.starts_with('-') => Some(addr.clone()),
+            _ => return Err("--http requires an address, e.g. --http 127.0.0.1:8080".into()),
+        },
+        None => None,
+    };
+
     // Create the Asana MCP server
     let server = AsanaServer::new()?;
 
+    if let Some(addr) = http_addr {
+        return serve_http(server, &addr).await;
+    }
+
     // Create STDIO transport and serve
     let service = server.serve(stdio()).await?;
 
@@ -65,6 +83,38 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Serve MCP over streamable HTTP/SSE at `/mcp` until interrupted.
+#[cfg(feature = "http")]
+async fn serve_http(server: AsanaServer, addr: &str) -> Result<(), Box<dyn std::error::Error>> {
+    use rmcp::transport::streamable_http_server::{
+        session::local::LocalSessionManager, StreamableHttpServerConfig, StreamableHttpService,
+    };
+
+    // Each session gets a clone of the server, sharing its client and connection pool
+    let service: StreamableHttpService<AsanaServer, LocalSessionManager> =
+        StreamableHttpService::new(
+            move || Ok(server.clone()),
+            Default::default(),
+            StreamableHttpServerConfig::default(),
+        );
+    let router = axum::Router::new().nest_service("/mcp", service);
+
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    eprintln!("Serving MCP at http://{}/mcp", listener.local_addr()?);
+    axum::serve(listener, router)
+        .with_graceful_shutdown(async {
+            let _ = tokio::signal::ctrl_c().await;
+        })
+        .await?;
+
+    Ok(())
+}
+
+#[cfg(not(feature = "http"))]
+async fn serve_http(_server: AsanaServer, _addr: &str) -> Result<(), Box<dyn std::error::Error>> {
+    Err("this build does not include HTTP support; rebuild with `--features http`".into())
+}
+
 fn print_version() {
     println!(
         "{} {} ({}{} {})",
@@ -85,15 +135,26 @@ USAGE:
 
 OPTIONS:
     --schema [TOOL]  Dump tool schemas (optionally filter by tool name)
+    --http <ADDR>    Serve MCP over streamable HTTP/SSE at http://<ADDR>/mcp instead of stdio
+                     (requires the `http` feature)
     -V, --version    Print version information
     -h, --help       Show this help message
 
 ENVIRONMENT:
     ASANA_TOKEN              Asana personal access token (required)
+    ASANA_TOKEN_FILE         File containing the token, used when ASANA_TOKEN is unset
     ASANA_DEFAULT_WORKSPACE  Default workspace GID (optional)
+    ASANA_TIMEOUT_SECS       Per-request timeout in seconds (optional, default 30)
+    ASANA_BASE_URL           API base URL (optional, default https://app.asana.com/api/1.0)
+    ASANA_ACCOUNTS           Comma-separated names of additional accounts (optional); each
+                             needs ASANA_<NAME>_TOKEN and may set ASANA_<NAME>_DEFAULT_WORKSPACE
+    ASANA_<RESOURCE>_FIELDS  Replace a default opt_fields list (optional), e.g.
+                             ASANA_PROJECT_FIELDS=gid,name,owner.name
 
 EXAMPLES:
     asanamcp                 Start MCP server on stdio
+    asanamcp --http 127.0.0.1:8080
+                             Serve MCP at http://127.0.0.1:8080/mcp
     asanamcp --schema        Dump all tool schemas
     asanamcp --schema get    Dump only asana_get schema
 "#
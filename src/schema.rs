@@ -28,6 +28,11 @@ pub fn dump_schemas(filter: Option<&str>) {
             description: "Update an existing Asana resource",
             schema: serde_json::to_value(schema_for!(UpdateParams)).unwrap(),
         },
+        ToolSchema {
+            name: "asana_delete",
+            description: "Delete an Asana resource (requires confirm: true)",
+            schema: serde_json::to_value(schema_for!(DeleteParams)).unwrap(),
+        },
         ToolSchema {
             name: "asana_link",
             description: "Add or remove relationships between resources",
@@ -43,6 +48,32 @@ pub fn dump_schemas(filter: Option<&str>) {
             description: "Search for resources by name (projects, templates, users, etc.)",
             schema: serde_json::to_value(schema_for!(ResourceSearchParams)).unwrap(),
         },
+        ToolSchema {
+            name: "asana_batch",
+            description: "Run up to 10 API calls in one request",
+            schema: serde_json::to_value(schema_for!(BatchParams)).unwrap(),
+        },
+        ToolSchema {
+            name: "asana_events",
+            description: "Poll for changes to a resource using sync tokens",
+            schema: serde_json::to_value(schema_for!(EventsParams)).unwrap(),
+        },
+        ToolSchema {
+            name: "asana_export",
+            description:
+                "Export a project with its sections, tasks, custom fields, and status updates",
+            schema: serde_json::to_value(schema_for!(ExportParams)).unwrap(),
+        },
+        ToolSchema {
+            name: "asana_complete",
+            description: "Mark many tasks complete or incomplete at once",
+            schema: serde_json::to_value(schema_for!(CompleteParams)).unwrap(),
+        },
+        ToolSchema {
+            name: "asana_request",
+            description: "Call any Asana API endpoint directly",
+            schema: serde_json::to_value(schema_for!(RequestParams)).unwrap(),
+        },
         ToolSchema {
             name: "asana_workspaces",
             description: "List all accessible workspaces",
@@ -66,7 +97,7 @@ pub fn dump_schemas(filter: Option<&str>) {
 
     if filtered.is_empty() {
         eprintln!("No matching tools found for filter: {:?}", filter);
-        eprintln!("Available tools: asana_get, asana_create, asana_update, asana_link, asana_task_search, asana_resource_search, asana_workspaces");
+        eprintln!("Available tools: asana_get, asana_create, asana_update, asana_delete, asana_link, asana_task_search, asana_resource_search, asana_batch, asana_events, asana_export, asana_complete, asana_request, asana_workspaces");
         std::process::exit(1);
     }
 
@@ -18,6 +18,18 @@ pub const PROJECT_FIELDS: &str = "gid,name,color,archived,public,owner,owner.nam
     current_status_update.title,current_status_update.text,\
     notes,created_at,modified_at,due_date,due_on,start_on,permalink_url,icon";
 
+/// Fields to request for a project's current status.
+pub const CURRENT_STATUS_FIELDS: &str = "gid,name,current_status_update,\
+    current_status_update.status_type,current_status_update.title,\
+    current_status_update.created_at,current_status_update.author.name";
+
+/// Fields to request for project task counts. Asana returns no counts unless asked.
+pub const TASK_COUNT_FIELDS: &str = "num_tasks,num_incomplete_tasks,num_completed_tasks,\
+    num_milestones,num_incomplete_milestones,num_completed_milestones";
+
+/// Fields to request for task dependencies and dependents.
+pub const DEPENDENCY_FIELDS: &str = "gid,name,resource_type,completed,permalink_url";
+
 /// Fields to request for portfolio resources.
 pub const PORTFOLIO_FIELDS: &str = "gid,name,color,owner,owner.name,workspace,\
     current_status_update,current_status_update.gid,current_status_update.status_type,\
@@ -43,8 +55,17 @@ pub const RECURSIVE_TASK_FIELDS: &str = "gid,name,resource_type,completed,comple
     projects,projects.name,workspace,tags,memberships,memberships.project,\
     memberships.project.name,memberships.section,memberships.section.name";
 
+/// Fields to request for tasks in the user's My Tasks list.
+pub const MY_TASK_FIELDS: &str = "gid,name,resource_type,completed,completed_at,\
+    assignee,assignee.name,due_on,due_at,start_on,notes,created_at,modified_at,\
+    permalink_url,parent,parent.name,num_likes,num_subtasks,liked,\
+    projects,projects.name,workspace,tags,memberships,memberships.project,\
+    memberships.project.name,memberships.section,memberships.section.name,\
+    assignee_section,assignee_section.name";
+
 /// Fields to request for subtasks.
-pub const SUBTASK_FIELDS: &str = "gid,name,completed,assignee,assignee.name,due_on,num_subtasks";
+pub const SUBTASK_FIELDS: &str =
+    "gid,name,completed,assignee,assignee.name,due_on,num_subtasks,permalink_url";
 
 /// Fields to request for stories/comments.
 pub const STORY_FIELDS: &str = "gid,created_at,created_by,created_by.name,\
@@ -52,7 +73,7 @@ pub const STORY_FIELDS: &str = "gid,created_at,created_by,created_by.name,\
 
 /// Fields to request for status updates.
 pub const STATUS_UPDATE_FIELDS: &str = "gid,resource_subtype,title,text,html_text,status_type,\
-    created_at,created_by,created_by.name,modified_at,parent,parent.name";
+    created_at,created_by,created_by.name,modified_at,parent,parent.name,num_likes";
 
 /// Fields to request for workspaces.
 pub const WORKSPACE_FIELDS: &str = "gid,name,is_organization";
@@ -62,6 +83,18 @@ pub const TEMPLATE_FIELDS: &str = "gid,name,description,html_description,owner,o
     team,team.name,public,requested_dates,requested_dates.gid,requested_dates.name,\
     requested_dates.description,requested_roles,requested_roles.gid,requested_roles.name,color";
 
+/// Fields needed to list a project template's requested variables.
+pub const TEMPLATE_VARIABLE_FIELDS: &str = "name,requested_dates.name,requested_dates.description,\
+    requested_roles.name";
+
+/// Fields to request for task templates.
+pub const TASK_TEMPLATE_FIELDS: &str =
+    "gid,name,project,project.name,created_by,created_by.name,created_at,template";
+
+/// Fields to request for async jobs.
+pub const JOB_FIELDS: &str = "gid,resource_subtype,status,new_project,new_project.name,\
+    new_project.permalink_url,new_task,new_task.name,new_task.permalink_url";
+
 /// Fields to request for sections.
 pub const SECTION_FIELDS: &str = "gid,name,project,project.name,created_at";
 
@@ -75,6 +108,14 @@ pub const USER_FIELDS: &str = "gid,name,email,photo,workspaces,workspaces.name";
 /// Fields to request for teams.
 pub const TEAM_FIELDS: &str = "gid,name,description,html_description,organization,permalink_url";
 
+/// Fields to request for project and portfolio memberships.
+pub const MEMBERSHIP_FIELDS: &str = "gid,user,user.name,user.email,access_level,\
+    project,project.name,portfolio,portfolio.name";
+
+/// Fields to request for team memberships, which have roles instead of access levels.
+pub const TEAM_MEMBERSHIP_FIELDS: &str =
+    "gid,user,user.name,user.email,team,team.name,is_admin,is_guest,is_limited_access";
+
 /// Fields to request for custom field settings.
 pub const CUSTOM_FIELD_SETTINGS_FIELDS: &str = "gid,custom_field,custom_field.gid,\
     custom_field.name,custom_field.type,custom_field.enum_options,\
@@ -82,10 +123,89 @@ pub const CUSTOM_FIELD_SETTINGS_FIELDS: &str = "gid,custom_field,custom_field.gi
     custom_field.enum_options.color,custom_field.precision,\
     custom_field.currency_code,is_important,project";
 
+/// Fields to request for custom field definitions.
+pub const CUSTOM_FIELD_FIELDS: &str = "gid,name,resource_subtype,type,description,precision,\
+    format,currency_code,is_global_to_workspace,enum_options,enum_options.gid,\
+    enum_options.name,enum_options.color,enum_options.enabled";
+
+/// Fields to request for enum options.
+pub const ENUM_OPTION_FIELDS: &str = "gid,name,color,enabled";
+
+/// Search result fields as a literal, so the presets below can extend it with `concat!`.
+macro_rules! search_fields {
+    () => {
+        "gid,name,completed,assignee,assignee.name,\
+         due_on,start_on,projects,projects.name,tags,tags.name,permalink_url"
+    };
+}
+
 /// Fields to request for search results.
-pub const SEARCH_FIELDS: &str = "gid,name,completed,assignee,assignee.name,\
-    due_on,start_on,projects,projects.name,tags,tags.name,permalink_url";
+pub const SEARCH_FIELDS: &str = search_fields!();
+
+/// Fields for task search results at `detail_level: minimal`.
+pub const SEARCH_MINIMAL_FIELDS: &str = "gid,name,completed";
+
+/// Fields for task search results at `detail_level: full`.
+pub const SEARCH_FULL_FIELDS: &str = concat!(
+    search_fields!(),
+    ",custom_fields,custom_fields.name,custom_fields.display_value"
+);
+
+/// Fields to request for goal time periods.
+pub const TIME_PERIOD_FIELDS: &str =
+    "gid,display_name,period,start_on,end_on,parent,parent.display_name";
+
+/// Fields needed to summarize tasks into counts.
+pub const TASK_SUMMARY_FIELDS: &str = "gid,completed,assignee.name,due_on,due_at";
 
 /// Fields to request for project briefs (the "Key Resources" section on Overview tab, NOT the Note tab).
 pub const PROJECT_BRIEF_FIELDS: &str =
     "gid,title,text,html_text,permalink_url,project,project.name";
+
+/// Fields to request for goals.
+pub const GOAL_FIELDS: &str = "gid,name,notes,owner,owner.name,status,due_on,start_on,\
+    is_workspace_level,time_period,time_period.display_name,team,team.name,\
+    metric,metric.current_number_value,metric.target_number_value,\
+    metric.initial_number_value,metric.unit,metric.progress_source,permalink_url";
+
+/// Fields to request for goal relationships (supporting work).
+pub const GOAL_RELATIONSHIP_FIELDS: &str = "gid,resource_subtype,contribution_weight,\
+    supported_goal,supported_goal.name,supporting_resource,supporting_resource.name,\
+    supporting_resource.resource_type";
+
+/// Fields to request for attachments.
+pub const ATTACHMENT_FIELDS: &str = "gid,name,resource_subtype,host,size,download_url,\
+    view_url,permanent_url,created_at,parent,parent.name,parent.resource_type";
+
+/// Fields to request for webhooks.
+pub const WEBHOOK_FIELDS: &str = "gid,active,resource,resource.name,resource.resource_type,\
+    target,created_at,last_success_at,last_failure_at,last_failure_content,filters";
+
+/// Fields to request after liking or unliking a task or story.
+pub const LIKE_FIELDS: &str = "gid,liked,num_likes";
+
+/// Curated defaults that can be overridden with `ASANA_<NAME>_FIELDS`, keyed by name.
+pub const CONFIGURABLE_FIELDS: &[(&str, &str)] = &[
+    ("project", PROJECT_FIELDS),
+    ("portfolio", PORTFOLIO_FIELDS),
+    ("task", TASK_FULL_FIELDS),
+    ("project_task", RECURSIVE_TASK_FIELDS),
+    ("my_task", MY_TASK_FIELDS),
+    ("subtask", SUBTASK_FIELDS),
+    ("story", STORY_FIELDS),
+    ("status_update", STATUS_UPDATE_FIELDS),
+    ("workspace", WORKSPACE_FIELDS),
+    ("template", TEMPLATE_FIELDS),
+    ("task_template", TASK_TEMPLATE_FIELDS),
+    ("section", SECTION_FIELDS),
+    ("tag", TAG_FIELDS),
+    ("user", USER_FIELDS),
+    ("team", TEAM_FIELDS),
+    ("membership", MEMBERSHIP_FIELDS),
+    ("custom_field", CUSTOM_FIELD_FIELDS),
+    ("search", SEARCH_FIELDS),
+    ("goal", GOAL_FIELDS),
+    ("time_period", TIME_PERIOD_FIELDS),
+    ("attachment", ATTACHMENT_FIELDS),
+    ("webhook", WEBHOOK_FIELDS),
+];
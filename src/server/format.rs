@@ -0,0 +1,559 @@
+//! Human-readable renderers for tool responses.
+//!
+//! JSON stays the default output. These renderers back the `format` parameter
+//! for callers that prefer a compact summary or spreadsheet-ready rows over raw
+//! API payloads.
+
+use serde_json::{Map, Value};
+
+use crate::types::{ProjectExport, Resource, TaskWithContext};
+
+/// Maximum number of comments included in a task summary (the most recent ones).
+const MAX_RECENT_COMMENTS: usize = 5;
+
+/// Look up a possibly nested field by dotted path (e.g. `assignee.name`).
+///
+/// Returns `None` for missing and null values.
+pub fn field_value<'a>(fields: &'a Map<String, Value>, path: &str) -> Option<&'a Value> {
+    let mut parts = path.split('.');
+    let mut value = fields.get(parts.next()?)?;
+    for part in parts {
+        value = value.get(part)?;
+    }
+    Some(value).filter(|v| !v.is_null())
+}
+
+/// Look up a non-empty string field by dotted path.
+fn field_str<'a>(fields: &'a Map<String, Value>, path: &str) -> Option<&'a str> {
+    field_value(fields, path)
+        .and_then(Value::as_str)
+        .filter(|s| !s.is_empty())
+}
+
+/// Join the `name` of each object in an array field (e.g. `projects`).
+fn field_names(fields: &Map<String, Value>, path: &str) -> Option<String> {
+    let names: Vec<&str> = field_value(fields, path)?
+        .as_array()?
+        .iter()
+        .filter_map(|item| item.get("name").and_then(Value::as_str))
+        .collect();
+    (!names.is_empty()).then(|| names.join(", "))
+}
+
+/// Collapse multi-line text onto a single line for bullet lists.
+fn one_line(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Render a task with its subtasks, dependencies, and comments as markdown.
+pub fn task_markdown(task: &TaskWithContext) -> String {
+    let fields = &task.task.fields;
+    let mut out = format!(
+        "# {}\n\n",
+        field_str(fields, "name").unwrap_or("(untitled task)")
+    );
+
+    let completed = field_value(fields, "completed")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    out.push_str(&format!(
+        "- **Status:** {}\n",
+        if completed { "Completed" } else { "Open" }
+    ));
+    out.push_str(&format!(
+        "- **Assignee:** {}\n",
+        field_str(fields, "assignee.name").unwrap_or("Unassigned")
+    ));
+    if let Some(due) = field_str(fields, "due_at").or_else(|| field_str(fields, "due_on")) {
+        out.push_str(&format!("- **Due:** {}\n", due));
+    }
+    if let Some(projects) = field_names(fields, "projects") {
+        out.push_str(&format!("- **Projects:** {}\n", projects));
+    }
+    if let Some(url) = field_str(fields, "permalink_url") {
+        out.push_str(&format!("- **Link:** {}\n", url));
+    }
+    if let Some(notes) = field_str(fields, "notes") {
+        out.push_str(&format!("\n{}\n", notes.trim()));
+    }
+
+    if !task.subtasks.is_empty() {
+        out.push_str("\n## Subtasks\n\n");
+        for subtask in &task.subtasks {
+            out.push_str(&format!(
+                "- [{}] {}\n",
+                if subtask.completed { "x" } else { " " },
+                subtask.name.as_deref().unwrap_or(&subtask.gid)
+            ));
+        }
+    }
+
+    for (heading, deps) in [
+        ("Blocked by", &task.dependencies),
+        ("Blocking", &task.dependents),
+    ] {
+        if !deps.is_empty() {
+            out.push_str(&format!("\n## {}\n\n", heading));
+            for dep in deps {
+                out.push_str(&format!("- {}\n", dep.name.as_deref().unwrap_or(&dep.gid)));
+            }
+        }
+    }
+
+    if !task.comments.is_empty() {
+        out.push_str("\n## Recent comments\n\n");
+        let skip = task.comments.len().saturating_sub(MAX_RECENT_COMMENTS);
+        for comment in task.comments.iter().skip(skip) {
+            let author = field_str(&comment.fields, "created_by.name").unwrap_or("Unknown");
+            let date = field_str(&comment.fields, "created_at")
+                .map(|at| format!(" ({})", at.get(..10).unwrap_or(at)))
+                .unwrap_or_default();
+            out.push_str(&format!(
+                "- **{}**{}: {}\n",
+                author,
+                date,
+                one_line(comment.text.as_deref().unwrap_or(""))
+            ));
+        }
+    }
+
+    out
+}
+
+/// Render a project as a markdown summary.
+pub fn project_markdown(project: &Resource) -> String {
+    let fields = &project.fields;
+    let mut out = format!(
+        "# {}\n\n",
+        field_str(fields, "name").unwrap_or("(untitled project)")
+    );
+
+    if let Some(status) = field_str(fields, "current_status_update.status_type") {
+        match field_str(fields, "current_status_update.title") {
+            Some(title) => out.push_str(&format!("- **Status:** {} ({})\n", status, title)),
+            None => out.push_str(&format!("- **Status:** {}\n", status)),
+        }
+    }
+    if field_value(fields, "archived").and_then(Value::as_bool) == Some(true) {
+        out.push_str("- **Archived:** yes\n");
+    }
+    if let Some(owner) = field_str(fields, "owner.name") {
+        out.push_str(&format!("- **Owner:** {}\n", owner));
+    }
+    if let Some(team) = field_str(fields, "team.name") {
+        out.push_str(&format!("- **Team:** {}\n", team));
+    }
+    if let Some(start) = field_str(fields, "start_on") {
+        out.push_str(&format!("- **Start:** {}\n", start));
+    }
+    if let Some(due) = field_str(fields, "due_on").or_else(|| field_str(fields, "due_date")) {
+        out.push_str(&format!("- **Due:** {}\n", due));
+    }
+    if let Some(url) = field_str(fields, "permalink_url") {
+        out.push_str(&format!("- **Link:** {}\n", url));
+    }
+    if let Some(notes) = field_str(fields, "notes") {
+        out.push_str(&format!("\n{}\n", notes.trim()));
+    }
+
+    out
+}
+
+/// Render a project export as markdown: the project summary, then custom fields,
+/// status updates, and a checklist of tasks per section with subtasks indented.
+pub fn project_export_markdown(export: &ProjectExport) -> String {
+    let mut out = project_markdown(&export.project);
+
+    let custom_fields: Vec<&str> = export
+        .custom_field_settings
+        .iter()
+        .filter_map(|setting| field_str(&setting.fields, "custom_field.name"))
+        .collect();
+    if !custom_fields.is_empty() {
+        out.push_str(&format!(
+            "\n**Custom fields:** {}\n",
+            custom_fields.join(", ")
+        ));
+    }
+
+    if !export.status_updates.is_empty() {
+        out.push_str("\n## Status updates\n\n");
+        for update in &export.status_updates {
+            let fields = &update.fields;
+            let date = field_str(fields, "created_at")
+                .map(|at| format!(" ({})", at.get(..10).unwrap_or(at)))
+                .unwrap_or_default();
+            out.push_str(&format!(
+                "- **{}**{}: {}\n",
+                field_str(fields, "status_type").unwrap_or("update"),
+                date,
+                one_line(field_str(fields, "title").unwrap_or(""))
+            ));
+        }
+    }
+
+    for section in &export.sections {
+        out.push_str(&format!(
+            "\n## {}\n\n",
+            field_str(&section.section.fields, "name").unwrap_or("(untitled section)")
+        ));
+        if section.tasks.is_empty() {
+            out.push_str("_No tasks_\n");
+        }
+
+        // Subtasks follow their parent, so each task's depth is its parent's plus one
+        let mut depths: std::collections::HashMap<&str, usize> = Default::default();
+        for task in &section.tasks {
+            let depth = field_str(&task.fields, "parent.gid")
+                .and_then(|parent| depths.get(parent))
+                .map_or(0, |d| d + 1);
+            depths.insert(&task.gid, depth);
+
+            let completed = field_value(&task.fields, "completed")
+                .and_then(Value::as_bool)
+                .unwrap_or(false);
+            let mut line = format!(
+                "{}- [{}] {}",
+                "  ".repeat(depth),
+                if completed { "x" } else { " " },
+                field_str(&task.fields, "name").unwrap_or(&task.gid)
+            );
+            if let Some(assignee) = field_str(&task.fields, "assignee.name") {
+                line.push_str(&format!(" (@{})", assignee));
+            }
+            if let Some(due) = field_str(&task.fields, "due_on") {
+                line.push_str(&format!(" due {}", due));
+            }
+            out.push_str(&line);
+            out.push('\n');
+        }
+    }
+
+    out
+}
+
+/// Render resources as CSV, one row per resource and one column per field.
+///
+/// `fields` is the comma-separated opt_fields list used for the request, so nested
+/// fields like `assignee.name` become dotted column headers. Missing fields are left
+/// empty; array fields (e.g. `projects.name`) are joined with `; `.
+pub fn resources_csv(resources: &[Resource], fields: &str) -> String {
+    let columns: Vec<&str> = fields.split(',').filter(|f| !f.is_empty()).collect();
+    let mut out = csv_row(columns.iter().map(|c| c.to_string()));
+
+    for resource in resources {
+        let value = serde_json::to_value(resource).unwrap_or(Value::Null);
+        out.push_str(&csv_row(columns.iter().map(|column| {
+            let parts: Vec<&str> = column.split('.').collect();
+            csv_cell(&value, &parts)
+        })));
+    }
+
+    out
+}
+
+/// Render the value at a dotted path as a single CSV cell, mapping over arrays.
+fn csv_cell(value: &Value, path: &[&str]) -> String {
+    match (value, path.split_first()) {
+        (Value::Array(items), _) => items
+            .iter()
+            .map(|item| csv_cell(item, path))
+            .filter(|cell| !cell.is_empty())
+            .collect::<Vec<_>>()
+            .join("; "),
+        (_, Some((first, rest))) => value
+            .get(first)
+            .map(|next| csv_cell(next, rest))
+            .unwrap_or_default(),
+        (Value::Null, None) => String::new(),
+        (Value::String(s), None) => s.clone(),
+        (Value::Object(obj), None) => obj
+            .get("name")
+            .or_else(|| obj.get("gid"))
+            .map(|v| csv_cell(v, &[]))
+            .unwrap_or_else(|| value.to_string()),
+        (other, None) => other.to_string(),
+    }
+}
+
+/// Join cells into a CSV line, quoting cells that contain separators or quotes.
+fn csv_row(cells: impl Iterator<Item = String>) -> String {
+    let cells: Vec<String> = cells
+        .map(|cell| {
+            if cell.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", cell.replace('"', "\"\""))
+            } else {
+                cell
+            }
+        })
+        .collect();
+    format!("{}\n", cells.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{SectionExport, Story, TaskDependency, TaskRef};
+
+    fn resource(value: Value) -> Resource {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn comment(author: &str, created_at: &str, text: &str) -> Story {
+        serde_json::from_value(serde_json::json!({
+            "gid": format!("story-{}", created_at),
+            "resource_subtype": "comment_added",
+            "text": text,
+            "created_at": created_at,
+            "created_by": {"gid": "u1", "name": author}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_field_value_follows_dotted_paths() {
+        let task = resource(serde_json::json!({
+            "gid": "1",
+            "assignee": {"gid": "u1", "name": "Alice"},
+            "due_on": null
+        }));
+
+        assert_eq!(
+            field_value(&task.fields, "assignee.name"),
+            Some(&serde_json::json!("Alice"))
+        );
+        assert_eq!(field_value(&task.fields, "assignee.email"), None);
+        assert_eq!(field_value(&task.fields, "due_on"), None);
+    }
+
+    #[test]
+    fn test_task_markdown_full_context() {
+        let task = TaskWithContext {
+            task: resource(serde_json::json!({
+                "gid": "t1",
+                "name": "Ship release",
+                "completed": false,
+                "assignee": {"gid": "u1", "name": "Alice"},
+                "due_on": "2024-06-14",
+                "projects": [{"gid": "p1", "name": "Launch"}, {"gid": "p2", "name": "Q2"}],
+                "permalink_url": "https://app.asana.com/0/p1/t1",
+                "notes": "Cut the tag and publish.\n"
+            })),
+            subtasks: vec![
+                TaskRef {
+                    gid: "s1".to_string(),
+                    name: Some("Write notes".to_string()),
+                    completed: true,
+                    num_subtasks: 0,
+                },
+                TaskRef {
+                    gid: "s2".to_string(),
+                    name: Some("Tag build".to_string()),
+                    completed: false,
+                    num_subtasks: 0,
+                },
+            ],
+            dependencies: vec![TaskDependency {
+                gid: "d1".to_string(),
+                name: Some("Fix CI".to_string()),
+                resource_type: Some("task".to_string()),
+                completed: None,
+            }],
+            dependents: vec![],
+            comments: vec![comment(
+                "Bob",
+                "2024-06-10T09:30:00.000Z",
+                "Looks good\nto me",
+            )],
+            has_more_comments: false,
+        };
+
+        let md = task_markdown(&task);
+
+        assert!(md.starts_with("# Ship release\n"));
+        assert!(md.contains("- **Status:** Open\n"));
+        assert!(md.contains("- **Assignee:** Alice\n"));
+        assert!(md.contains("- **Due:** 2024-06-14\n"));
+        assert!(md.contains("- **Projects:** Launch, Q2\n"));
+        assert!(md.contains("- **Link:** https://app.asana.com/0/p1/t1\n"));
+        assert!(md.contains("\nCut the tag and publish.\n"));
+        assert!(md.contains("## Subtasks\n\n- [x] Write notes\n- [ ] Tag build\n"));
+        assert!(md.contains("## Blocked by\n\n- Fix CI\n"));
+        assert!(!md.contains("## Blocking"));
+        assert!(md.contains("- **Bob** (2024-06-10): Looks good to me\n"));
+    }
+
+    #[test]
+    fn test_task_markdown_minimal_task() {
+        let task = TaskWithContext {
+            task: resource(serde_json::json!({"gid": "t1", "name": "Bare", "completed": true})),
+            subtasks: vec![],
+            dependencies: vec![],
+            dependents: vec![],
+            comments: vec![],
+            has_more_comments: false,
+        };
+
+        let md = task_markdown(&task);
+
+        assert!(md.contains("- **Status:** Completed\n"));
+        assert!(md.contains("- **Assignee:** Unassigned\n"));
+        assert!(!md.contains("Due"));
+        assert!(!md.contains("##"));
+    }
+
+    #[test]
+    fn test_task_markdown_keeps_only_recent_comments() {
+        let comments = (1..=7)
+            .map(|day| {
+                comment(
+                    "Alice",
+                    &format!("2024-06-0{}T00:00:00Z", day),
+                    &format!("Update {}", day),
+                )
+            })
+            .collect();
+        let task = TaskWithContext {
+            task: resource(serde_json::json!({"gid": "t1", "name": "Busy"})),
+            subtasks: vec![],
+            dependencies: vec![],
+            dependents: vec![],
+            comments,
+            has_more_comments: false,
+        };
+
+        let md = task_markdown(&task);
+
+        assert!(!md.contains("Update 2\n"));
+        assert!(md.contains("Update 3\n"));
+        assert!(md.contains("Update 7\n"));
+    }
+
+    #[test]
+    fn test_resources_csv_uses_fields_as_header() {
+        let tasks = vec![
+            resource(serde_json::json!({
+                "gid": "1",
+                "name": "Write docs",
+                "completed": true,
+                "assignee": {"gid": "u1", "name": "Alice"},
+                "due_on": "2024-06-14"
+            })),
+            resource(serde_json::json!({
+                "gid": "2",
+                "name": "Fix bug",
+                "completed": false,
+                "assignee": null
+            })),
+        ];
+
+        let csv = resources_csv(&tasks, "gid,name,completed,assignee.name,due_on");
+
+        assert_eq!(
+            csv,
+            "gid,name,completed,assignee.name,due_on\n\
+             1,Write docs,true,Alice,2024-06-14\n\
+             2,Fix bug,false,,\n"
+        );
+    }
+
+    #[test]
+    fn test_resources_csv_escapes_and_flattens() {
+        let tasks = vec![resource(serde_json::json!({
+            "gid": "1",
+            "name": "Plan \"Q3\", roughly",
+            "notes": "line one\nline two",
+            "projects": [{"gid": "p1", "name": "Launch"}, {"gid": "p2", "name": "Q3"}],
+            "parent": {"gid": "t0"}
+        }))];
+
+        let csv = resources_csv(&tasks, "name,notes,projects.name,parent,missing.field");
+
+        assert_eq!(
+            csv,
+            "name,notes,projects.name,parent,missing.field\n\
+             \"Plan \"\"Q3\"\", roughly\",\"line one\nline two\",Launch; Q3,t0,\n"
+        );
+    }
+
+    #[test]
+    fn test_project_markdown() {
+        let project = resource(serde_json::json!({
+            "gid": "p1",
+            "name": "Launch",
+            "archived": false,
+            "owner": {"gid": "u1", "name": "Alice"},
+            "team": {"gid": "tm1", "name": "Platform"},
+            "current_status_update": {"gid": "su1", "status_type": "on_track", "title": "Week 3"},
+            "due_on": "2024-07-01",
+            "permalink_url": "https://app.asana.com/0/p1",
+            "notes": ""
+        }));
+
+        let md = project_markdown(&project);
+
+        assert!(md.starts_with("# Launch\n"));
+        assert!(md.contains("- **Status:** on_track (Week 3)\n"));
+        assert!(md.contains("- **Owner:** Alice\n"));
+        assert!(md.contains("- **Team:** Platform\n"));
+        assert!(md.contains("- **Due:** 2024-07-01\n"));
+        assert!(!md.contains("Archived"));
+        assert!(md.ends_with("- **Link:** https://app.asana.com/0/p1\n"));
+    }
+
+    #[test]
+    fn test_project_export_markdown_indents_subtasks() {
+        let export = ProjectExport {
+            project: resource(serde_json::json!({"gid": "p1", "name": "Launch"})),
+            custom_field_settings: vec![],
+            status_updates: vec![resource(serde_json::json!({
+                "gid": "su1",
+                "status_type": "at_risk",
+                "title": "Slipping",
+                "created_at": "2024-06-03T10:00:00.000Z"
+            }))],
+            sections: vec![
+                SectionExport {
+                    section: resource(serde_json::json!({"gid": "s1", "name": "Build"})),
+                    tasks: vec![
+                        resource(serde_json::json!({
+                            "gid": "t1",
+                            "name": "API",
+                            "completed": false,
+                            "assignee": {"gid": "u1", "name": "Alice"},
+                            "due_on": "2024-07-01"
+                        })),
+                        resource(serde_json::json!({
+                            "gid": "t2",
+                            "name": "Endpoints",
+                            "completed": true,
+                            "parent": {"gid": "t1"}
+                        })),
+                        resource(serde_json::json!({
+                            "gid": "t3",
+                            "name": "Tests",
+                            "parent": {"gid": "t2"}
+                        })),
+                    ],
+                },
+                SectionExport {
+                    section: resource(serde_json::json!({"gid": "s2", "name": "Ship"})),
+                    tasks: vec![],
+                },
+            ],
+        };
+
+        let md = project_export_markdown(&export);
+
+        assert!(md.starts_with("# Launch\n"));
+        assert!(!md.contains("Custom fields"));
+        assert!(md.contains("## Status updates\n\n- **at_risk** (2024-06-03): Slipping\n"));
+        assert!(md.contains(
+            "## Build\n\n\
+             - [ ] API (@Alice) due 2024-07-01\n\
+             \x20\x20- [x] Endpoints\n\
+             \x20\x20\x20\x20- [ ] Tests\n"
+        ));
+        assert!(md.ends_with("## Ship\n\n_No tasks_\n"));
+    }
+}
@@ -1,10 +1,21 @@
 //! Helper functions for the MCP server.
 
+use crate::client::AsanaClient;
+use crate::types::{
+    LimitedList, PortfolioItem, PortfolioItemExpanded, PortfolioWithItems, Resource, SectionGroup,
+    TaskSummary,
+};
 use crate::Error;
-use rmcp::model::{CallToolResult, Content, ErrorCode, ErrorData as McpError};
+use chrono::{DateTime, Days, NaiveDate, Utc};
+use rmcp::model::{CallToolResult, Content, ErrorCode, ErrorData as McpError, RawContent};
 use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 
-use super::params::{GetParams, LinkParams, TaskSearchParams};
+use super::format::resources_csv;
+use super::params::{ColorValue, GetParams, LinkParams, OutputFormat, TaskSearchParams};
 
 /// Convert depth parameter to Option<usize>.
 ///
@@ -18,12 +29,130 @@ pub fn depth_to_option(depth: i32) -> Option<usize> {
     }
 }
 
+/// Validate a depth parameter and convert it with [`depth_to_option`].
+///
+/// Returns a validation error naming `param` for values below -1.
+pub fn checked_depth(depth: i32, param: &str) -> Result<Option<usize>, McpError> {
+    if depth < -1 {
+        return Err(validation_error(&format!(
+            "{} must be -1 (unlimited) or at least 0, got {}",
+            param, depth
+        )));
+    }
+    Ok(depth_to_option(depth))
+}
+
+/// Marker recorded when the server's maximum depth stopped an expansion.
+pub const DEPTH_LIMIT_REACHED: &str = "depth limit reached";
+
+/// Marker recorded when the request budget stopped an expansion.
+pub const REQUEST_BUDGET_EXHAUSTED: &str = "request budget exhausted";
+
+/// Depth and request limits for one recursive fetch.
+///
+/// Clones share the request budget, so concurrent branches (and the portfolio and
+/// subtask levels of the same fetch) draw from a single total.
+#[derive(Debug, Clone)]
+pub struct Traversal {
+    max_depth: usize,
+    depth: usize,
+    capped: bool,
+    remaining: Arc<AtomicUsize>,
+}
+
+impl Traversal {
+    /// Start a traversal to `requested` levels (`None` = unlimited), clamped to `max_depth`,
+    /// that may make up to `budget` expansion requests.
+    pub fn new(requested: Option<usize>, max_depth: usize, budget: usize) -> Self {
+        Self {
+            max_depth,
+            depth: 0,
+            capped: false,
+            remaining: Arc::new(AtomicUsize::new(budget)),
+        }
+        .with_depth(requested)
+    }
+
+    /// The same traversal budget with a different requested depth.
+    pub fn with_depth(&self, requested: Option<usize>) -> Self {
+        let depth = requested.map_or(self.max_depth, |d| d.min(self.max_depth));
+        Self {
+            depth,
+            capped: requested.is_none_or(|d| d > self.max_depth),
+            ..self.clone()
+        }
+    }
+
+    /// Reserve one request, or return false once the budget is spent.
+    pub fn spend(&self) -> bool {
+        self.remaining
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| n.checked_sub(1))
+            .is_ok()
+    }
+
+    /// Whether to expand `resource`'s children at `current_depth`, reserving a request if so.
+    ///
+    /// When a server limit rather than the requested depth prevents it, the reason is
+    /// recorded in the resource's `truncated` field.
+    pub fn expand(&self, current_depth: usize, resource: &mut Resource) -> bool {
+        if current_depth >= self.depth {
+            if self.capped {
+                mark_truncated(resource, DEPTH_LIMIT_REACHED);
+            }
+            return false;
+        }
+        if !self.spend() {
+            mark_truncated(resource, REQUEST_BUDGET_EXHAUSTED);
+            return false;
+        }
+        true
+    }
+}
+
+/// Marker recorded on a portfolio that contains one of its own ancestors.
+pub const PORTFOLIO_CYCLE: &str = "portfolio cycle";
+
+/// A portfolio item as listed by `/items`, left unexpanded and marked with `reason`.
+///
+/// Returns `None` for item types that portfolios don't expand.
+pub fn unexpanded_portfolio_item(
+    item: PortfolioItem,
+    reason: &str,
+) -> Option<PortfolioItemExpanded> {
+    let mut stub = Resource {
+        gid: item.gid,
+        resource_type: Some(item.resource_type.clone()),
+        fields: serde_json::Map::new(),
+    };
+    stub.fields
+        .insert("name".to_string(), serde_json::json!(item.name));
+    mark_truncated(&mut stub, reason);
+    match item.resource_type.as_str() {
+        "project" => Some(PortfolioItemExpanded::Project(Box::new(stub))),
+        "portfolio" => Some(PortfolioItemExpanded::Portfolio(Box::new(
+            PortfolioWithItems {
+                portfolio: stub,
+                items: Vec::new(),
+                errors: Vec::new(),
+            },
+        ))),
+        _ => None,
+    }
+}
+
+/// Record why a resource was not fully expanded.
+pub fn mark_truncated(resource: &mut Resource, reason: &str) {
+    resource
+        .fields
+        .insert("truncated".to_string(), serde_json::json!(reason));
+}
+
 /// Convert an Error to an appropriate MCP error with proper error code.
 ///
 /// Maps error types to MCP error codes:
 /// - NotFound -> INVALID_PARAMS (resource doesn't exist)
-/// - MissingToken, InvalidToken -> INVALID_PARAMS (auth config issue)
-/// - Api, Http, Parse -> INTERNAL_ERROR (server/network issue)
+/// - MissingToken, InvalidToken, Config -> INVALID_PARAMS (configuration issue)
+/// - Api, Http, Parse, RateLimited -> INTERNAL_ERROR (server/network issue)
 pub fn error_to_mcp(context: &str, error: Error) -> McpError {
     let (code, message) = match &error {
         Error::NotFound(resource) => (
@@ -32,21 +161,56 @@ pub fn error_to_mcp(context: &str, error: Error) -> McpError {
         ),
         Error::MissingToken => (
             ErrorCode::INVALID_PARAMS,
-            format!("{}: ASANA_TOKEN environment variable not set", context),
+            format!(
+                "{}: ASANA_TOKEN environment variable not set (or ASANA_TOKEN_FILE is empty)",
+                context
+            ),
         ),
         Error::InvalidToken => (
             ErrorCode::INVALID_PARAMS,
             format!("{}: invalid token format", context),
         ),
+        Error::Config(msg) => (ErrorCode::INVALID_PARAMS, format!("{}: {}", context, msg)),
+        Error::Unauthorized(msg) => (
+            ErrorCode::INVALID_PARAMS,
+            format!(
+                "{}: {} (your ASANA_TOKEN may be invalid or expired)",
+                context, msg
+            ),
+        ),
+        Error::Validation { message, help } => (
+            ErrorCode::INVALID_PARAMS,
+            match help {
+                Some(help) => format!("{}: {} ({})", context, message, help),
+                None => format!("{}: {}", context, message),
+            },
+        ),
+        Error::Forbidden(msg) => (
+            ErrorCode::INVALID_PARAMS,
+            format!(
+                "{}: {} (your ASANA_TOKEN lacks access to this resource or the required scope)",
+                context, msg
+            ),
+        ),
         Error::Api { message: msg } => (ErrorCode::INTERNAL_ERROR, format!("{}: {}", context, msg)),
         Error::Http(e) => (
             ErrorCode::INTERNAL_ERROR,
             format!("{}: HTTP error - {}", context, e),
         ),
+        Error::Timeout(_) => (
+            ErrorCode::INTERNAL_ERROR,
+            format!(
+                "{}: request timed out (ASANA_TIMEOUT_SECS raises the limit)",
+                context
+            ),
+        ),
         Error::Parse(e) => (
             ErrorCode::INTERNAL_ERROR,
             format!("{}: failed to parse response - {}", context, e),
         ),
+        Error::RateLimited { .. } | Error::DryRun { .. } => {
+            (ErrorCode::INTERNAL_ERROR, format!("{}: {}", context, error))
+        }
     };
 
     McpError::new(code, message, None)
@@ -68,11 +232,647 @@ pub fn json_response<T: Serialize>(value: &T) -> Result<CallToolResult, McpError
     Ok(CallToolResult::success(vec![Content::text(json)]))
 }
 
+/// Serialize a created or updated resource, with any warnings about the request.
+///
+/// Without warnings this is [`json_response`]. Otherwise the resource is wrapped as
+/// `data` next to a `warnings` list so the caller sees what may need fixing.
+pub fn warnings_response<T: Serialize>(
+    value: &T,
+    warnings: &[String],
+) -> Result<CallToolResult, McpError> {
+    if warnings.is_empty() {
+        return json_response(value);
+    }
+    json_response(&serde_json::json!({
+        "data": value,
+        "warnings": warnings,
+    }))
+}
+
+/// Default maximum length, in characters, of long text fields in `asana_get` responses.
+pub const DEFAULT_MAX_TEXT_LEN: usize = 10_000;
+
+/// Default number of most recent comments returned for a task.
+pub const DEFAULT_COMMENT_LIMIT: usize = 50;
+
+/// Convert a `comment_limit` parameter to a cap: default when unset, `None` (no cap) for 0.
+pub fn comment_limit(limit: Option<usize>) -> Option<usize> {
+    match limit.unwrap_or(DEFAULT_COMMENT_LIMIT) {
+        0 => None,
+        n => Some(n),
+    }
+}
+
+/// Serialize the most recent stories of a task to a JSON response.
+///
+/// Complete lists are returned as a plain array. When older stories were left out, the
+/// list is wrapped with a `has_more` marker so the caller knows to raise `comment_limit`.
+pub fn recent_stories_response<T: Serialize>(
+    list: &LimitedList<T>,
+) -> Result<CallToolResult, McpError> {
+    if !list.has_more {
+        return json_response(&list.data);
+    }
+    json_response(&serde_json::json!({
+        "data": list.data,
+        "has_more": true,
+        "message": format!(
+            "Showing the {} most recent; older ones exist. Raise comment_limit to see more.",
+            list.data.len()
+        ),
+    }))
+}
+
+/// Text fields that can hold arbitrarily large bodies (task notes, comments, briefs).
+const LONG_TEXT_FIELDS: &[&str] = &["notes", "html_notes", "text", "html_text"];
+
+/// Marker appended to text fields cut short by [`truncate_text_fields`].
+const TRUNCATION_MARKER: &str = "…(truncated)";
+
+/// Truncate long text fields in a JSON response to `max_len` characters.
+///
+/// Each JSON text content is parsed, `notes`/`html_notes`/`text`/`html_text` values at any
+/// depth are shortened with a truncation marker, and the JSON is re-serialized. Non-JSON
+/// content (markdown, CSV) is returned unchanged, as is everything when `max_len` is 0.
+pub fn truncate_text_fields(mut result: CallToolResult, max_len: usize) -> CallToolResult {
+    if max_len == 0 {
+        return result;
+    }
+    for content in &mut result.content {
+        let RawContent::Text(text) = &mut content.raw else {
+            continue;
+        };
+        let Ok(mut value) = serde_json::from_str::<serde_json::Value>(&text.text) else {
+            continue;
+        };
+        if truncate_value(&mut value, max_len) {
+            if let Ok(json) = serde_json::to_string_pretty(&value) {
+                text.text = json;
+            }
+        }
+    }
+    result
+}
+
+/// Recursively truncate long text fields, returning whether anything changed.
+fn truncate_value(value: &mut serde_json::Value, max_len: usize) -> bool {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut changed = false;
+            for (key, field) in map.iter_mut() {
+                if let serde_json::Value::String(s) = field {
+                    if LONG_TEXT_FIELDS.contains(&key.as_str()) && s.chars().count() > max_len {
+                        let mut cut: String = s.chars().take(max_len).collect();
+                        cut.push_str(TRUNCATION_MARKER);
+                        *s = cut;
+                        changed = true;
+                    }
+                } else {
+                    changed |= truncate_value(field, max_len);
+                }
+            }
+            changed
+        }
+        serde_json::Value::Array(items) => items.iter_mut().fold(false, |changed, item| {
+            truncate_value(item, max_len) | changed
+        }),
+        _ => false,
+    }
+}
+
+/// Rewrite `custom_fields` arrays in a JSON response as `{field name: value}` maps.
+///
+/// Enum values become the option name, multi-enum and people values lists of names, and
+/// dates the date (or date-time) string. Fields whose value wasn't requested fall back to
+/// `display_value`. Non-JSON content (markdown, CSV) is returned unchanged.
+pub fn flatten_custom_fields(mut result: CallToolResult) -> CallToolResult {
+    for content in &mut result.content {
+        let RawContent::Text(text) = &mut content.raw else {
+            continue;
+        };
+        let Ok(mut value) = serde_json::from_str::<Value>(&text.text) else {
+            continue;
+        };
+        if flatten_custom_fields_value(&mut value) {
+            if let Ok(json) = serde_json::to_string_pretty(&value) {
+                text.text = json;
+            }
+        }
+    }
+    result
+}
+
+/// Recursively flatten `custom_fields` arrays, returning whether anything changed.
+fn flatten_custom_fields_value(value: &mut Value) -> bool {
+    match value {
+        Value::Object(map) => {
+            let mut changed = false;
+            for (key, field) in map.iter_mut() {
+                if key == "custom_fields" {
+                    if let Value::Array(fields) = field {
+                        *field = Value::Object(flatten_custom_field_list(fields));
+                        changed = true;
+                        continue;
+                    }
+                }
+                changed |= flatten_custom_fields_value(field);
+            }
+            changed
+        }
+        Value::Array(items) => items.iter_mut().fold(false, |changed, item| {
+            flatten_custom_fields_value(item) | changed
+        }),
+        _ => false,
+    }
+}
+
+/// Map custom field objects to `{name: value}`, keying unnamed or duplicate names by GID.
+fn flatten_custom_field_list(fields: &[Value]) -> serde_json::Map<String, Value> {
+    let mut flattened = serde_json::Map::new();
+    for field in fields {
+        let gid = field
+            .get("gid")
+            .and_then(|g| g.as_str())
+            .unwrap_or_default();
+        let key = match field.get("name").and_then(|n| n.as_str()) {
+            Some(name) if !flattened.contains_key(name) => name.to_string(),
+            Some(name) => format!("{} ({})", name, gid),
+            None => gid.to_string(),
+        };
+        flattened.insert(key, custom_field_value(field));
+    }
+    flattened
+}
+
+/// The plain value of a custom field, chosen by its type when known.
+fn custom_field_value(field: &Value) -> Value {
+    fn names(values: &Value) -> Value {
+        let names = values.as_array().into_iter().flatten().map(|v| {
+            v.get("name")
+                .or_else(|| v.get("gid"))
+                .cloned()
+                .unwrap_or(Value::Null)
+        });
+        Value::Array(names.collect())
+    }
+
+    let subtype = field
+        .get("resource_subtype")
+        .or_else(|| field.get("type"))
+        .and_then(|t| t.as_str());
+    let key = match subtype {
+        Some("enum") => "enum_value",
+        Some("multi_enum") => "multi_enum_values",
+        Some("number") => "number_value",
+        Some("text") => "text_value",
+        Some("date") => "date_value",
+        Some("people") => "people_value",
+        _ => [
+            "enum_value",
+            "multi_enum_values",
+            "number_value",
+            "text_value",
+            "date_value",
+            "people_value",
+        ]
+        .into_iter()
+        .find(|k| field.get(k).is_some())
+        .unwrap_or("display_value"),
+    };
+
+    let Some(value) = field.get(key) else {
+        return field.get("display_value").cloned().unwrap_or(Value::Null);
+    };
+    match key {
+        "enum_value" => value.get("name").cloned().unwrap_or(Value::Null),
+        "multi_enum_values" | "people_value" => names(value),
+        "date_value" => value
+            .get("date_time")
+            .filter(|d| !d.is_null())
+            .or_else(|| value.get("date"))
+            .cloned()
+            .unwrap_or(Value::Null),
+        _ => value.clone(),
+    }
+}
+
+/// Serialize a possibly-truncated list to a JSON response.
+///
+/// Complete lists are returned as a plain array. Truncated lists are wrapped with a
+/// `has_more` marker so the caller knows to narrow the query or raise the limit.
+/// With `with_metadata`, every list is wrapped as `{count, truncated, data}` instead.
+pub fn limited_json_response<T: Serialize>(
+    list: &LimitedList<T>,
+    with_metadata: bool,
+) -> Result<CallToolResult, McpError> {
+    if with_metadata {
+        return json_response(&serde_json::json!({
+            "count": list.data.len(),
+            "truncated": list.has_more,
+            "data": list.data,
+        }));
+    }
+    if !list.has_more {
+        return json_response(&list.data);
+    }
+    json_response(&serde_json::json!({
+        "data": list.data,
+        "has_more": true,
+        "message": format!(
+            "Showing the first {} results; more exist. Narrow the query or raise limit to see more.",
+            list.data.len()
+        ),
+    }))
+}
+
+/// Create a plain-text response, e.g. for markdown output.
+pub fn text_response(text: String) -> Result<CallToolResult, McpError> {
+    Ok(CallToolResult::success(vec![Content::text(text)]))
+}
+
+/// Create a response for a (possibly truncated) list of resources in the requested format.
+///
+/// CSV output uses `fields` as the header row, followed by a trailing `#` comment line
+/// when the list was truncated or `with_metadata` is set. JSON and markdown fall back to
+/// [`limited_json_response`].
+pub fn limited_list_response(
+    list: &LimitedList<Resource>,
+    fields: &str,
+    format: OutputFormat,
+    with_metadata: bool,
+) -> Result<CallToolResult, McpError> {
+    match format {
+        OutputFormat::Csv => {
+            let mut csv = resources_csv(&list.data, fields);
+            if with_metadata {
+                csv.push_str(&format!(
+                    "# count: {}, truncated: {}\n",
+                    list.data.len(),
+                    list.has_more
+                ));
+            } else if list.has_more {
+                csv.push_str(&format!(
+                    "# Showing the first {} results; more exist. Narrow the query or raise limit to see more.\n",
+                    list.data.len()
+                ));
+            }
+            text_response(csv)
+        }
+        OutputFormat::Json | OutputFormat::Markdown => limited_json_response(list, with_metadata),
+    }
+}
+
+/// Create a response for a complete list of resources in the requested format.
+pub fn list_response(
+    items: &[Resource],
+    fields: &str,
+    format: OutputFormat,
+) -> Result<CallToolResult, McpError> {
+    match format {
+        OutputFormat::Csv => text_response(resources_csv(items, fields)),
+        OutputFormat::Json | OutputFormat::Markdown => json_response(&items),
+    }
+}
+
+/// Aggregate tasks into counts: total, completed, overdue, and per assignee.
+///
+/// A task is overdue when it is incomplete and its `due_at` (or `due_on`, for date-only
+/// due dates) is before `now`.
+pub fn summarize_tasks(tasks: &[Resource], now: DateTime<Utc>) -> TaskSummary {
+    let today = now.date_naive();
+    let mut summary = TaskSummary {
+        total: tasks.len(),
+        ..Default::default()
+    };
+
+    for task in tasks {
+        let field = |name: &str| task.fields.get(name);
+        let completed = field("completed").and_then(|v| v.as_bool()) == Some(true);
+
+        if completed {
+            summary.completed += 1;
+        } else {
+            summary.incomplete += 1;
+            let due_at = field("due_at")
+                .and_then(|v| v.as_str())
+                .and_then(|s| DateTime::parse_from_rfc3339(s).ok());
+            let due_on = field("due_on")
+                .and_then(|v| v.as_str())
+                .and_then(|s| s.parse::<NaiveDate>().ok());
+            let overdue = match (due_at, due_on) {
+                (Some(due_at), _) => due_at < now,
+                (None, Some(due_on)) => due_on < today,
+                (None, None) => false,
+            };
+            if overdue {
+                summary.overdue += 1;
+            }
+        }
+
+        let assignee = field("assignee")
+            .and_then(|a| a.get("name"))
+            .and_then(|n| n.as_str())
+            .unwrap_or("Unassigned");
+        *summary.by_assignee.entry(assignee.to_string()).or_default() += 1;
+    }
+
+    summary
+}
+
+/// Group tasks by their `assignee_section`, keeping sections in order of first appearance.
+pub fn group_by_assignee_section(tasks: Vec<Resource>) -> Vec<SectionGroup> {
+    let mut groups: Vec<SectionGroup> = Vec::new();
+
+    for task in tasks {
+        let section = task
+            .fields
+            .get("assignee_section")
+            .filter(|s| !s.is_null())
+            .cloned();
+        let section_gid = section.as_ref().and_then(|s| s.get("gid")).cloned();
+        let existing = groups
+            .iter_mut()
+            .find(|g| g.section.as_ref().and_then(|s| s.get("gid")).cloned() == section_gid);
+        match existing {
+            Some(group) => group.tasks.push(task),
+            None => groups.push(SectionGroup {
+                section,
+                tasks: vec![task],
+            }),
+        }
+    }
+
+    groups
+}
+
+/// Pick the most recently created status update.
+///
+/// Updates without a parseable `created_at` only win when none has one, in which case
+/// the first update (Asana lists newest first) is kept.
+pub fn latest_status_update(updates: Vec<Resource>) -> Option<Resource> {
+    let created_at = |update: &Resource| {
+        update
+            .fields
+            .get("created_at")
+            .and_then(|v| v.as_str())
+            .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+    };
+
+    let mut latest: Option<Resource> = None;
+    for update in updates {
+        match &latest {
+            Some(current) if created_at(&update) <= created_at(current) => {}
+            _ => latest = Some(update),
+        }
+    }
+    latest
+}
+
+/// Resolve relative due-date filters to `(due_on.after, due_on.before)` search bounds.
+///
+/// Asana treats both bounds as exclusive, so "due within N days" (today through today + N)
+/// becomes `after = today - 1, before = today + N + 1`, and "overdue" is `before = today`.
+pub fn relative_due_bounds(
+    today: NaiveDate,
+    due_within_days: Option<u32>,
+    overdue: bool,
+) -> (Option<NaiveDate>, Option<NaiveDate>) {
+    if overdue {
+        return (None, Some(today));
+    }
+    match due_within_days {
+        Some(days) => (
+            today.pred_opt(),
+            today.checked_add_days(Days::new(u64::from(days) + 1)),
+        ),
+        None => (None, None),
+    }
+}
+
+/// Warning for a project, portfolio, or tag color that is not in Asana's palette.
+///
+/// The color is still sent so Asana has the final say, but it usually means a typo.
+pub fn unknown_color_warning(color: &ColorValue) -> Option<String> {
+    match color {
+        ColorValue::Other(other) => Some(format!(
+            "color {:?} is not in Asana's palette; Asana may reject it",
+            other
+        )),
+        ColorValue::Palette(_) => None,
+    }
+}
+
+/// Reject a task boundary given both as a date (`due_on`, `start_on`) and a date-time
+/// (`due_at`, `start_at`); Asana accepts only one form of each.
+pub fn check_task_dates(
+    due_on: &Option<String>,
+    due_at: &Option<String>,
+    start_on: &Option<String>,
+    start_at: &Option<String>,
+) -> Result<(), McpError> {
+    if due_on.is_some() && due_at.is_some() {
+        return Err(validation_error("Provide only one of due_on or due_at"));
+    }
+    if start_on.is_some() && start_at.is_some() {
+        return Err(validation_error("Provide only one of start_on or start_at"));
+    }
+    Ok(())
+}
+
 /// Create a validation error with the given message.
 pub fn validation_error(message: &str) -> McpError {
     McpError::new(ErrorCode::INVALID_PARAMS, message.to_string(), None)
 }
 
+/// Whether a value looks like an Asana GID (a non-empty string of digits).
+pub fn is_gid(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Whether any key or value in a `custom_fields` map is a name rather than a GID.
+pub fn custom_fields_need_lookup(values: &HashMap<String, Value>) -> bool {
+    let is_name = |v: &Value| v.as_str().is_some_and(|s| !is_gid(s));
+    values.iter().any(|(key, value)| {
+        !is_gid(key)
+            || is_name(value)
+            || value
+                .as_array()
+                .is_some_and(|items| items.iter().any(is_name))
+    })
+}
+
+/// Replace custom field names and enum option names in `values` with their GIDs.
+///
+/// `fields` are the custom field definitions that apply (with `name` and `enum_options`).
+/// Names match case-insensitively. GID keys are kept, and values of non-enum fields are
+/// left alone. An unknown or ambiguous name is an error listing the candidates.
+pub fn resolve_custom_field_names(
+    values: HashMap<String, Value>,
+    fields: &[Resource],
+) -> Result<HashMap<String, Value>, McpError> {
+    fn name_of(option: &Value) -> &str {
+        option
+            .get("name")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+    }
+    let field_name = |f: &Resource| f.str_field("name").unwrap_or_default().to_string();
+
+    let mut resolved = HashMap::with_capacity(values.len());
+    for (key, value) in values {
+        let by_gid = fields.iter().find(|f| f.gid == key);
+        let field = if by_gid.is_some() || is_gid(&key) {
+            by_gid
+        } else {
+            let matches: Vec<&Resource> = fields
+                .iter()
+                .filter(|f| field_name(f).eq_ignore_ascii_case(&key))
+                .collect();
+            match matches.as_slice() {
+                [only] => Some(*only),
+                [] => {
+                    let names: Vec<String> = fields.iter().map(field_name).collect();
+                    return Err(validation_error(&format!(
+                        "No custom field named '{}'. Available: {}",
+                        key,
+                        names.join(", ")
+                    )));
+                }
+                _ => {
+                    let candidates: Vec<String> = matches
+                        .iter()
+                        .map(|f| format!("{} ({})", field_name(f), f.gid))
+                        .collect();
+                    return Err(validation_error(&format!(
+                        "Custom field name '{}' is ambiguous; use a GID instead. Candidates: {}",
+                        key,
+                        candidates.join(", ")
+                    )));
+                }
+            }
+        };
+
+        let options = field
+            .and_then(|f| f.fields.get("enum_options"))
+            .and_then(Value::as_array)
+            .filter(|options| !options.is_empty());
+        let value = match options {
+            Some(options) => {
+                let field = field.map(field_name).unwrap_or_default();
+                let resolve = |v: Value| -> Result<Value, McpError> {
+                    let is_option_gid = |s: &str| {
+                        options
+                            .iter()
+                            .any(|o| o.get("gid") == Some(&Value::from(s)))
+                    };
+                    let Some(name) = v.as_str().filter(|s| !is_gid(s) && !is_option_gid(s)) else {
+                        return Ok(v);
+                    };
+                    let matches: Vec<&Value> = options
+                        .iter()
+                        .filter(|o| name_of(o).eq_ignore_ascii_case(name))
+                        .collect();
+                    match matches.as_slice() {
+                        [only] => Ok(only.get("gid").cloned().unwrap_or(Value::Null)),
+                        [] => {
+                            let names: Vec<&str> = options.iter().map(name_of).collect();
+                            Err(validation_error(&format!(
+                                "Custom field '{}' has no option named '{}'. Options: {}",
+                                field,
+                                name,
+                                names.join(", ")
+                            )))
+                        }
+                        _ => Err(validation_error(&format!(
+                            "Option name '{}' is ambiguous in custom field '{}'; use an option GID instead",
+                            name, field
+                        ))),
+                    }
+                };
+                match value {
+                    Value::Array(items) => {
+                        Value::Array(items.into_iter().map(resolve).collect::<Result<_, _>>()?)
+                    }
+                    value => resolve(value)?,
+                }
+            }
+            None => value,
+        };
+
+        let key = field.map_or(key, |f| f.gid.clone());
+        resolved.insert(key, value);
+    }
+    Ok(resolved)
+}
+
+/// Resolve a resource name to its GID with a workspace typeahead lookup.
+///
+/// Values that already look like GIDs are returned unchanged. Otherwise the single
+/// case-insensitive exact name match is used, or the only result when there is no exact
+/// match. Anything else is an error listing the candidates.
+pub async fn resolve_name_to_gid(
+    client: &AsanaClient,
+    workspace_gid: &str,
+    resource_type: &str,
+    value: &str,
+) -> Result<String, McpError> {
+    if is_gid(value) {
+        return Ok(value.to_string());
+    }
+
+    let results: Vec<Resource> = client
+        .get_list(
+            &format!("/workspaces/{}/typeahead", workspace_gid),
+            &[
+                ("query", value),
+                ("resource_type", resource_type),
+                ("count", "10"),
+                ("opt_fields", "gid,name"),
+            ],
+        )
+        .await
+        .map_err(|e| error_to_mcp(&format!("Failed to resolve {} name", resource_type), e))?
+        .data;
+
+    let name_of = |r: &Resource| {
+        r.fields
+            .get("name")
+            .and_then(|n| n.as_str())
+            .unwrap_or_default()
+            .to_string()
+    };
+    let exact: Vec<&Resource> = results
+        .iter()
+        .filter(|r| name_of(r).eq_ignore_ascii_case(value))
+        .collect();
+
+    match (exact.as_slice(), results.as_slice()) {
+        ([only], _) => Ok(only.gid.clone()),
+        ([], [only]) => Ok(only.gid.clone()),
+        ([], []) => Err(validation_error(&format!(
+            "No {} named '{}' found",
+            resource_type, value
+        ))),
+        _ => {
+            let candidates = if exact.is_empty() {
+                results.iter().collect()
+            } else {
+                exact
+            };
+            let candidates: Vec<String> = candidates
+                .iter()
+                .map(|r| format!("{} ({})", name_of(r), r.gid))
+                .collect();
+            Err(validation_error(&format!(
+                "{} name '{}' is ambiguous; use a GID instead. Candidates: {}",
+                resource_type,
+                value,
+                candidates.join(", ")
+            )))
+        }
+    }
+}
+
 /// Require a GID, returning a validation error if not provided or empty.
 pub fn require_gid(gid: &Option<String>, resource_type: &str) -> Result<String, McpError> {
     match gid.as_ref().filter(|s| !s.is_empty()) {
@@ -91,8 +891,8 @@ pub fn success_response(message: &str) -> Result<CallToolResult, McpError> {
     )]))
 }
 
-use super::fields::MINIMAL_FIELDS;
-use super::params::DetailLevel;
+use super::fields::{MINIMAL_FIELDS, SEARCH_FULL_FIELDS, SEARCH_MINIMAL_FIELDS};
+use super::params::{DetailLevel, SearchDetailLevel};
 
 /// Resolve fields based on detail_level, extra_fields, and opt_fields.
 ///
@@ -108,16 +908,23 @@ pub fn resolve_fields_with_level(
     opt_fields: &Option<Vec<String>>,
     default_fields: &str,
 ) -> String {
-    // If opt_fields is explicitly provided, use those (full override)
-    if let Some(fields) = opt_fields.as_ref().filter(|f| !f.is_empty()) {
-        return fields.join(",");
-    }
-
-    // Start with base fields based on detail level
     let base = match detail_level {
         DetailLevel::Minimal => MINIMAL_FIELDS,
         DetailLevel::Default => default_fields,
     };
+    resolve_fields_with_base(base, extra_fields, opt_fields)
+}
+
+/// Explicit `opt_fields` if given, otherwise `base` plus any `extra_fields`.
+fn resolve_fields_with_base(
+    base: &str,
+    extra_fields: &Option<Vec<String>>,
+    opt_fields: &Option<Vec<String>>,
+) -> String {
+    // If opt_fields is explicitly provided, use those (full override)
+    if let Some(fields) = opt_fields.as_ref().filter(|f| !f.is_empty()) {
+        return fields.join(",");
+    }
 
     // If extra_fields provided, append them
     if let Some(extras) = extra_fields.as_ref().filter(|e| !e.is_empty()) {
@@ -127,6 +934,31 @@ pub fn resolve_fields_with_level(
     }
 }
 
+/// Append `?opt_fields=` to a create or update path.
+///
+/// Explicit `opt_fields` win and `minimal` requests just gid, name, and resource_type.
+/// Otherwise `default_fields` is requested, or, when that is `None`, no fields are
+/// requested and Asana returns its standard representation.
+pub fn path_with_fields(
+    path: &str,
+    detail_level: DetailLevel,
+    opt_fields: &Option<Vec<String>>,
+    default_fields: Option<&str>,
+) -> String {
+    let fields = match opt_fields.as_ref().filter(|f| !f.is_empty()) {
+        Some(fields) => Some(fields.join(",")),
+        None => match detail_level {
+            DetailLevel::Minimal => Some(MINIMAL_FIELDS.to_string()),
+            DetailLevel::Default => default_fields.map(str::to_string),
+        },
+    };
+
+    match fields {
+        Some(fields) => format!("{}?opt_fields={}", path, fields),
+        None => path.to_string(),
+    }
+}
+
 /// Helper to resolve fields from GetParams.
 pub fn resolve_fields_from_get_params(params: &GetParams, default_fields: &str) -> String {
     resolve_fields_with_level(
@@ -138,16 +970,19 @@ pub fn resolve_fields_from_get_params(params: &GetParams, default_fields: &str)
 }
 
 /// Helper to resolve fields from TaskSearchParams.
+///
+/// Search has its own presets: `Minimal` is [`SEARCH_MINIMAL_FIELDS`], `Default` is
+/// `default_fields`, and `Full` is [`SEARCH_FULL_FIELDS`].
 pub fn resolve_fields_from_task_search_params(
     params: &TaskSearchParams,
     default_fields: &str,
 ) -> String {
-    resolve_fields_with_level(
-        params.detail_level,
-        &params.extra_fields,
-        &params.opt_fields,
-        default_fields,
-    )
+    let base = match params.detail_level {
+        SearchDetailLevel::Minimal => SEARCH_MINIMAL_FIELDS,
+        SearchDetailLevel::Default => default_fields,
+        SearchDetailLevel::Full => SEARCH_FULL_FIELDS,
+    };
+    resolve_fields_with_base(base, &params.extra_fields, &params.opt_fields)
 }
 
 /// Extract item GIDs from link parameters.
@@ -171,6 +1006,242 @@ pub fn get_item_gids(p: &LinkParams) -> Result<Vec<String>, McpError> {
 mod tests {
     use super::*;
 
+    fn task(value: serde_json::Value) -> Resource {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_relative_due_bounds() {
+        let today = NaiveDate::from_ymd_opt(2024, 6, 12).unwrap();
+        let date = |d| NaiveDate::from_ymd_opt(2024, 6, d);
+
+        assert_eq!(relative_due_bounds(today, None, false), (None, None));
+        assert_eq!(relative_due_bounds(today, None, true), (None, date(12)));
+        assert_eq!(
+            relative_due_bounds(today, Some(7), false),
+            (date(11), date(20))
+        );
+        assert_eq!(
+            relative_due_bounds(today, Some(0), false),
+            (date(11), date(13))
+        );
+    }
+
+    #[test]
+    fn test_summarize_tasks_counts() {
+        let now = DateTime::parse_from_rfc3339("2024-06-12T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let tasks = vec![
+            task(serde_json::json!({
+                "gid": "1", "completed": true, "due_on": "2024-06-01",
+                "assignee": {"gid": "u1", "name": "Alice"}
+            })),
+            task(serde_json::json!({
+                "gid": "2", "completed": false, "due_on": "2024-06-11",
+                "assignee": {"gid": "u1", "name": "Alice"}
+            })),
+            task(serde_json::json!({
+                "gid": "3", "completed": false, "due_on": "2024-06-12",
+                "assignee": {"gid": "u2", "name": "Bob"}
+            })),
+            task(serde_json::json!({
+                "gid": "4", "completed": false, "due_at": "2024-06-12T09:00:00Z",
+                "assignee": null
+            })),
+            task(serde_json::json!({"gid": "5", "completed": false})),
+        ];
+
+        let summary = summarize_tasks(&tasks, now);
+
+        assert_eq!(summary.total, 5);
+        assert_eq!(summary.completed, 1);
+        assert_eq!(summary.incomplete, 4);
+        // Task 2 (due yesterday) and task 4 (due this morning); due today isn't overdue
+        assert_eq!(summary.overdue, 2);
+        assert_eq!(summary.by_assignee["Alice"], 2);
+        assert_eq!(summary.by_assignee["Bob"], 1);
+        assert_eq!(summary.by_assignee["Unassigned"], 2);
+    }
+
+    #[test]
+    fn test_summarize_tasks_completed_tasks_are_never_overdue() {
+        let now = Utc::now();
+        let tasks = vec![task(
+            serde_json::json!({"gid": "1", "completed": true, "due_on": "2000-01-01"}),
+        )];
+
+        let summary = summarize_tasks(&tasks, now);
+
+        assert_eq!(summary.overdue, 0);
+        assert_eq!(summary.completed, 1);
+    }
+
+    #[test]
+    fn test_summarize_tasks_empty() {
+        let summary = summarize_tasks(&[], Utc::now());
+        assert_eq!(summary, TaskSummary::default());
+    }
+
+    #[test]
+    fn test_group_by_assignee_section() {
+        let today = serde_json::json!({"gid": "s1", "name": "Today"});
+        let later = serde_json::json!({"gid": "s2", "name": "Later"});
+        let tasks = vec![
+            task(serde_json::json!({"gid": "1", "assignee_section": today})),
+            task(serde_json::json!({"gid": "2", "assignee_section": later})),
+            task(serde_json::json!({"gid": "3", "assignee_section": today})),
+            task(serde_json::json!({"gid": "4", "assignee_section": null})),
+            task(serde_json::json!({"gid": "5"})),
+        ];
+
+        let groups = group_by_assignee_section(tasks);
+
+        let gids = |g: &SectionGroup| g.tasks.iter().map(|t| t.gid.clone()).collect::<Vec<_>>();
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[0].section, Some(today));
+        assert_eq!(gids(&groups[0]), ["1", "3"]);
+        assert_eq!(groups[1].section, Some(later));
+        assert_eq!(gids(&groups[1]), ["2"]);
+        assert_eq!(groups[2].section, None);
+        assert_eq!(gids(&groups[2]), ["4", "5"]);
+    }
+
+    #[test]
+    fn test_latest_status_update_picks_newest_created_at() {
+        let updates = vec![
+            task(serde_json::json!({"gid": "1", "created_at": "2024-03-01T09:00:00.000Z"})),
+            task(serde_json::json!({"gid": "2", "created_at": "2024-05-01T09:00:00.000Z"})),
+            task(serde_json::json!({"gid": "3", "created_at": "2024-04-01T09:00:00.000Z"})),
+        ];
+
+        assert_eq!(latest_status_update(updates).unwrap().gid, "2");
+    }
+
+    #[test]
+    fn test_latest_status_update_without_dates_keeps_first() {
+        let updates = vec![
+            task(serde_json::json!({"gid": "1"})),
+            task(serde_json::json!({"gid": "2"})),
+        ];
+
+        assert_eq!(latest_status_update(updates).unwrap().gid, "1");
+        assert!(latest_status_update(Vec::new()).is_none());
+    }
+
+    fn response_json(result: &CallToolResult) -> serde_json::Value {
+        serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap()
+    }
+
+    #[test]
+    fn test_flatten_custom_fields_by_type() {
+        let result = json_response(&serde_json::json!({
+            "gid": "1",
+            "custom_fields": [
+                {"gid": "cf1", "name": "Priority", "resource_subtype": "enum",
+                 "enum_value": {"gid": "o1", "name": "High"}},
+                {"gid": "cf2", "name": "Labels", "resource_subtype": "multi_enum",
+                 "multi_enum_values": [{"gid": "o2", "name": "Bug"}, {"gid": "o3", "name": "UI"}]},
+                {"gid": "cf3", "name": "Estimate", "resource_subtype": "number", "number_value": 3.5},
+                {"gid": "cf4", "name": "Notes", "resource_subtype": "text", "text_value": "n/a"},
+                {"gid": "cf5", "name": "Launch", "resource_subtype": "date",
+                 "date_value": {"date": "2024-06-01", "date_time": null}},
+                {"gid": "cf6", "name": "Reviewers", "resource_subtype": "people",
+                 "people_value": [{"gid": "u1", "name": "Ada"}, {"gid": "u2", "name": "Grace"}]},
+                {"gid": "cf7", "name": "Stage", "resource_subtype": "enum", "enum_value": null}
+            ]
+        }))
+        .unwrap();
+
+        let json = response_json(&flatten_custom_fields(result));
+
+        assert_eq!(
+            json["custom_fields"],
+            serde_json::json!({
+                "Priority": "High",
+                "Labels": ["Bug", "UI"],
+                "Estimate": 3.5,
+                "Notes": "n/a",
+                "Launch": "2024-06-01",
+                "Reviewers": ["Ada", "Grace"],
+                "Stage": null
+            })
+        );
+    }
+
+    #[test]
+    fn test_flatten_custom_fields_nested_and_display_value_fallback() {
+        let result = json_response(&serde_json::json!([{
+            "gid": "1",
+            "subtasks": [{
+                "gid": "2",
+                "custom_fields": [
+                    {"gid": "cf1", "name": "Priority", "display_value": "Low"},
+                    {"gid": "cf2", "name": "Priority", "number_value": 1},
+                    {"gid": "cf3", "enum_value": {"name": "Open"}}
+                ]
+            }]
+        }]))
+        .unwrap();
+
+        let json = response_json(&flatten_custom_fields(result));
+
+        assert_eq!(
+            json[0]["subtasks"][0]["custom_fields"],
+            serde_json::json!({"Priority": "Low", "Priority (cf2)": 1, "cf3": "Open"})
+        );
+    }
+
+    #[test]
+    fn test_truncate_text_fields_shortens_nested_long_text() {
+        let result = json_response(&serde_json::json!({
+            "name": "A long name that is not a text field",
+            "notes": "abcdefghij",
+            "comments": [{"text": "0123456789", "html_text": "short"}]
+        }))
+        .unwrap();
+
+        let value = response_json(&truncate_text_fields(result, 5));
+
+        assert_eq!(value["name"], "A long name that is not a text field");
+        assert_eq!(value["notes"], "abcde…(truncated)");
+        assert_eq!(value["comments"][0]["text"], "01234…(truncated)");
+        assert_eq!(value["comments"][0]["html_text"], "short");
+    }
+
+    #[test]
+    fn test_truncate_text_fields_respects_char_boundaries() {
+        let result = json_response(&serde_json::json!({"notes": "héllo wörld"})).unwrap();
+
+        let value = response_json(&truncate_text_fields(result, 4));
+
+        assert_eq!(value["notes"], "héll…(truncated)");
+    }
+
+    #[test]
+    fn test_truncate_text_fields_zero_disables_and_skips_non_json() {
+        let long = "x".repeat(50);
+        let json = json_response(&serde_json::json!({"notes": long})).unwrap();
+        let markdown = text_response(format!("# Task\n\n{}", long)).unwrap();
+
+        assert_eq!(response_json(&truncate_text_fields(json, 0))["notes"], long);
+        assert_eq!(
+            truncate_text_fields(markdown, 5).content[0]
+                .as_text()
+                .unwrap()
+                .text,
+            format!("# Task\n\n{}", long)
+        );
+    }
+
+    #[test]
+    fn test_is_gid() {
+        assert!(is_gid("1234567890"));
+        assert!(!is_gid(""));
+        assert!(!is_gid("My Workspace"));
+        assert!(!is_gid("12ab"));
+    }
+
     #[test]
     fn test_depth_to_option_negative_is_unlimited() {
         assert_eq!(depth_to_option(-1), None);
@@ -189,6 +1260,88 @@ mod tests {
         assert_eq!(depth_to_option(100), Some(100));
     }
 
+    #[test]
+    fn test_resolve_custom_field_names() {
+        let fields = vec![
+            task(serde_json::json!({
+                "gid": "cf1",
+                "name": "Priority",
+                "enum_options": [{"gid": "o1", "name": "Low"}, {"gid": "o2", "name": "High"}]
+            })),
+            task(serde_json::json!({
+                "gid": "cf2",
+                "name": "Labels",
+                "enum_options": [{"gid": "o3", "name": "UI"}, {"gid": "o4", "name": "API"}]
+            })),
+            task(serde_json::json!({"gid": "cf3", "name": "Estimate"})),
+        ];
+        let values = HashMap::from([
+            ("PRIORITY".to_string(), serde_json::json!("high")),
+            ("cf2".to_string(), serde_json::json!(["UI", "o4"])),
+            ("Estimate".to_string(), serde_json::json!(3)),
+            ("999".to_string(), serde_json::json!("text")),
+        ]);
+        assert!(custom_fields_need_lookup(&values));
+
+        let resolved = resolve_custom_field_names(values, &fields).unwrap();
+        assert_eq!(resolved["cf1"], serde_json::json!("o2"));
+        assert_eq!(resolved["cf2"], serde_json::json!(["o3", "o4"]));
+        assert_eq!(resolved["cf3"], serde_json::json!(3));
+        assert_eq!(resolved["999"], serde_json::json!("text"));
+    }
+
+    #[test]
+    fn test_resolve_custom_field_names_errors() {
+        let fields = vec![
+            task(serde_json::json!({"gid": "cf1", "name": "Status"})),
+            task(serde_json::json!({"gid": "cf2", "name": "status"})),
+        ];
+
+        let ambiguous = HashMap::from([("Status".to_string(), serde_json::json!(1))]);
+        let err = resolve_custom_field_names(ambiguous, &fields).unwrap_err();
+        assert!(err.message.contains("ambiguous"));
+        assert!(err.message.contains("cf1") && err.message.contains("cf2"));
+
+        let unknown = HashMap::from([("Owner".to_string(), serde_json::json!(1))]);
+        let err = resolve_custom_field_names(unknown, &fields).unwrap_err();
+        assert!(err.message.contains("No custom field named 'Owner'"));
+
+        let gids = HashMap::from([("123".to_string(), serde_json::json!("456"))]);
+        assert!(!custom_fields_need_lookup(&gids));
+    }
+
+    #[test]
+    fn test_checked_depth_rejects_below_minus_one() {
+        assert_eq!(checked_depth(-1, "depth").unwrap(), None);
+        assert_eq!(checked_depth(3, "depth").unwrap(), Some(3));
+        let err = checked_depth(-2, "subtask_depth").unwrap_err();
+        assert!(err.message.contains("subtask_depth"));
+    }
+
+    #[test]
+    fn test_traversal_caps_depth_and_shares_budget() {
+        let unlimited = Traversal::new(None, 2, 1);
+        let mut deep = task(serde_json::json!({"gid": "t1"}));
+        assert!(!unlimited.expand(2, &mut deep));
+        assert_eq!(deep.str_field("truncated"), Some(DEPTH_LIMIT_REACHED));
+
+        // An explicit depth under the cap stops without a marker
+        let shallow = unlimited.with_depth(Some(0));
+        let mut leaf = task(serde_json::json!({"gid": "t2"}));
+        assert!(!shallow.expand(0, &mut leaf));
+        assert_eq!(leaf.str_field("truncated"), None);
+
+        // The clone draws from the same single-request budget
+        let mut first = task(serde_json::json!({"gid": "t3"}));
+        let mut second = task(serde_json::json!({"gid": "t4"}));
+        assert!(unlimited.with_depth(Some(1)).expand(0, &mut first));
+        assert!(!unlimited.expand(0, &mut second));
+        assert_eq!(
+            second.str_field("truncated"),
+            Some(REQUEST_BUDGET_EXHAUSTED)
+        );
+    }
+
     #[test]
     fn test_error_to_mcp_not_found() {
         let error = Error::NotFound("project: Unknown object: 999".to_string());
@@ -221,6 +1374,40 @@ mod tests {
         assert_eq!(mcp_error.message, "Failed to search tasks: Rate limited");
     }
 
+    #[test]
+    fn test_error_to_mcp_unauthorized() {
+        let error = Error::Unauthorized("Not Authorized".to_string());
+        let mcp_error = error_to_mcp("Failed to get task", error);
+
+        assert_eq!(mcp_error.code, ErrorCode::INVALID_PARAMS);
+        assert!(mcp_error.message.contains("Not Authorized"));
+        assert!(mcp_error.message.contains("ASANA_TOKEN may be invalid"));
+    }
+
+    #[test]
+    fn test_error_to_mcp_forbidden() {
+        let error = Error::Forbidden("Not authorized".to_string());
+        let mcp_error = error_to_mcp("Failed to get task", error);
+
+        assert_eq!(mcp_error.code, ErrorCode::INVALID_PARAMS);
+        assert!(mcp_error.message.contains("lacks access"));
+    }
+
+    #[test]
+    fn test_error_to_mcp_validation_includes_help() {
+        let error = Error::Validation {
+            message: "custom_fields: Not a valid enum option".to_string(),
+            help: Some("Use the enum option GID".to_string()),
+        };
+        let mcp_error = error_to_mcp("Failed to update task", error);
+
+        assert_eq!(mcp_error.code, ErrorCode::INVALID_PARAMS);
+        assert_eq!(
+            mcp_error.message,
+            "Failed to update task: custom_fields: Not a valid enum option (Use the enum option GID)"
+        );
+    }
+
     #[test]
     fn test_error_to_mcp_invalid_token() {
         let error = Error::InvalidToken;
@@ -241,6 +1428,20 @@ mod tests {
         assert!(mcp_error.message.contains("parse"));
     }
 
+    #[test]
+    fn test_error_to_mcp_rate_limited() {
+        let error = Error::RateLimited {
+            retry_after: Some(std::time::Duration::from_secs(30)),
+        };
+        let mcp_error = error_to_mcp("Failed to get project", error);
+
+        assert_eq!(mcp_error.code, ErrorCode::INTERNAL_ERROR);
+        assert_eq!(
+            mcp_error.message,
+            "Failed to get project: rate limited by Asana API (retry after 30s)"
+        );
+    }
+
     #[test]
     fn test_to_mcp_error() {
         let mcp_error = to_mcp_error("Serialization", "unexpected EOF");
@@ -270,6 +1471,11 @@ mod tests {
             insert_before: None,
             insert_after: None,
             access_level: None,
+            contribution_weight: None,
+            resolve_names: false,
+            workspace_gid: None,
+            dry_run: false,
+            account: None,
         };
 
         let result = get_item_gids(&params).unwrap();
@@ -288,6 +1494,11 @@ mod tests {
             insert_before: None,
             insert_after: None,
             access_level: None,
+            contribution_weight: None,
+            resolve_names: false,
+            workspace_gid: None,
+            dry_run: false,
+            account: None,
         };
 
         let result = get_item_gids(&params).unwrap();
@@ -306,6 +1517,11 @@ mod tests {
             insert_before: None,
             insert_after: None,
             access_level: None,
+            contribution_weight: None,
+            resolve_names: false,
+            workspace_gid: None,
+            dry_run: false,
+            account: None,
         };
 
         let result = get_item_gids(&params);
@@ -325,6 +1541,11 @@ mod tests {
             insert_before: None,
             insert_after: None,
             access_level: None,
+            contribution_weight: None,
+            resolve_names: false,
+            workspace_gid: None,
+            dry_run: false,
+            account: None,
         };
 
         let result = get_item_gids(&params);
@@ -403,4 +1624,29 @@ mod tests {
             resolve_fields_with_level(DetailLevel::Default, &None, &Some(vec![]), "default_fields");
         assert_eq!(result, "default_fields");
     }
+
+    #[test]
+    fn test_path_with_fields() {
+        assert_eq!(
+            path_with_fields("/tasks", DetailLevel::Default, &None, None),
+            "/tasks"
+        );
+        assert_eq!(
+            path_with_fields("/webhooks", DetailLevel::Default, &None, Some("gid,active")),
+            "/webhooks?opt_fields=gid,active"
+        );
+        assert_eq!(
+            path_with_fields("/tasks", DetailLevel::Minimal, &None, None),
+            format!("/tasks?opt_fields={}", MINIMAL_FIELDS)
+        );
+        assert_eq!(
+            path_with_fields(
+                "/tasks",
+                DetailLevel::Minimal,
+                &Some(vec!["gid".to_string(), "permalink_url".to_string()]),
+                None
+            ),
+            "/tasks?opt_fields=gid,permalink_url"
+        );
+    }
 }
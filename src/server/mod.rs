@@ -1,15 +1,20 @@
 //! MCP server implementation for Asana.
 
 mod fields;
+mod format;
 mod helpers;
 pub mod params;
 
-use crate::client::AsanaClient;
+use crate::client::{account_env_var, AsanaClient, MAX_BATCH_ACTIONS};
 use crate::types::{
-    FavoriteError, FavoriteItem, FavoritesResponse, Job, PortfolioItem, PortfolioItemExpanded,
-    PortfolioWithItems, Resource, Story, TaskDependency, TaskWithContext,
+    CompleteResponse, DryRunResponse, FavoriteError, FavoriteItem, FavoritesResponse, Job,
+    LimitedList, PortfolioItem, PortfolioItemError, PortfolioItemExpanded, PortfolioWithItems,
+    ProjectExport, Resource, SectionExport, Story, TaskDependency, TaskError, TaskWithContext,
+    TemplateVariables, WhoAmI,
 };
 use crate::Error;
+use chrono::{NaiveDate, Utc};
+use futures::stream::{self, StreamExt, TryStreamExt};
 use rmcp::handler::server::router::tool::ToolRouter;
 use rmcp::handler::server::wrapper::Parameters;
 use rmcp::model::{
@@ -17,19 +22,76 @@ use rmcp::model::{
     ServerInfo,
 };
 use rmcp::{tool, tool_handler, tool_router, ServerHandler};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::pin::pin;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use fields::*;
 use helpers::*;
 pub use params::*;
 
+/// Default number of concurrent requests made while expanding subtasks or portfolio items.
+pub const DEFAULT_CONCURRENCY: usize = 8;
+
+/// Default time to wait for an instantiation or duplication job when `wait_for_job` is set.
+pub const DEFAULT_JOB_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Default deepest level expanded when a recursive fetch asks for unlimited depth.
+pub const DEFAULT_MAX_DEPTH: usize = 10;
+
+/// Default number of expansion requests one recursive fetch may make.
+pub const DEFAULT_REQUEST_BUDGET: usize = 500;
+
+/// Number of recent status updates included in a project export.
+const EXPORT_STATUS_UPDATES: usize = 5;
+
+/// Number of audit log events returned when no `limit` is given.
+const DEFAULT_AUDIT_LOG_LIMIT: usize = 100;
+
+/// Error for `format: csv` on a single resource, which has no rows to write.
+const CSV_LIST_ONLY: &str = "csv is only supported for list resource types";
+
+/// First delay between job status polls; doubles up to `JOB_POLL_MAX_DELAY`.
+const JOB_POLL_INITIAL_DELAY: Duration = Duration::from_millis(250);
+const JOB_POLL_MAX_DELAY: Duration = Duration::from_secs(4);
+
 /// MCP server for Asana operations.
 #[derive(Debug, Clone)]
 pub struct AsanaServer {
     client: AsanaClient,
     default_workspace_gid: Option<String>,
+    /// Workspace GIDs resolved from names, keyed by lowercased name.
+    workspace_gids_by_name: Arc<Mutex<HashMap<String, String>>>,
+    /// The user's only workspace, detected when no default is configured.
+    detected_workspace_gid: Arc<Mutex<Option<String>>>,
+    /// Additional accounts selectable per tool call, keyed by name.
+    accounts: Arc<BTreeMap<String, Account>>,
+    /// Replacements for curated default field lists, keyed by the default they replace.
+    field_overrides: Arc<HashMap<&'static str, String>>,
+    concurrency: usize,
+    max_depth: usize,
+    request_budget: usize,
+    job_timeout: Duration,
     tool_router: ToolRouter<AsanaServer>,
 }
 
+/// A named account: its client, default workspace, and workspace lookup caches.
+#[derive(Debug, Clone)]
+struct Account {
+    client: AsanaClient,
+    default_workspace_gid: Option<String>,
+    workspace_gids_by_name: Arc<Mutex<HashMap<String, String>>>,
+    detected_workspace_gid: Arc<Mutex<Option<String>>>,
+}
+
+/// Apply the retry policy used for every client the server creates from the environment.
+fn with_default_retries(client: AsanaClient) -> AsanaClient {
+    client
+        .with_retry_on_rate_limit(true)
+        .with_retry(3, std::time::Duration::from_millis(500))
+}
+
 #[tool_router]
 impl AsanaServer {
     /// Create a new Asana MCP server.
@@ -37,22 +99,182 @@ impl AsanaServer {
     /// Reads configuration from environment variables:
     /// - `ASANA_TOKEN` or `ASANA_ACCESS_TOKEN`: API token (required)
     /// - `ASANA_DEFAULT_WORKSPACE`: Default workspace GID (optional)
+    /// - `ASANA_ACCOUNTS`: Comma-separated names of additional accounts (optional), each
+    ///   configured with `ASANA_<NAME>_TOKEN` and optionally `ASANA_<NAME>_DEFAULT_WORKSPACE`
+    /// - `ASANA_<RESOURCE>_FIELDS`: Replacement default field lists (optional), see
+    ///   [`AsanaServer::with_default_fields`]
     pub fn new() -> Result<Self, Error> {
-        let client = AsanaClient::from_env()?;
+        let client = with_default_retries(AsanaClient::from_env()?);
         let default_workspace_gid = std::env::var("ASANA_DEFAULT_WORKSPACE").ok();
-        Ok(Self {
+        let mut server = Self {
             client,
             default_workspace_gid,
+            workspace_gids_by_name: Arc::default(),
+            detected_workspace_gid: Arc::default(),
+            accounts: Arc::default(),
+            field_overrides: Arc::default(),
+            concurrency: DEFAULT_CONCURRENCY,
+            max_depth: DEFAULT_MAX_DEPTH,
+            request_budget: DEFAULT_REQUEST_BUDGET,
+            job_timeout: DEFAULT_JOB_TIMEOUT,
             tool_router: Self::tool_router(),
+        };
+
+        let names = std::env::var("ASANA_ACCOUNTS").unwrap_or_default();
+        for name in names.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+            let client = with_default_retries(AsanaClient::from_env_account(name)?);
+            let default_workspace = std::env::var(account_env_var(name, "DEFAULT_WORKSPACE")).ok();
+            server = server.with_account(name, client, default_workspace.as_deref());
+        }
+
+        for (name, _) in CONFIGURABLE_FIELDS {
+            let var = format!("ASANA_{}_FIELDS", name.to_uppercase());
+            if let Ok(fields) = std::env::var(&var) {
+                if !fields.trim().is_empty() {
+                    server = server.with_default_fields(name, &fields)?;
+                }
+            }
+        }
+
+        Ok(server)
+    }
+
+    /// Replace the curated default fields for a resource, e.g. `"project"` or `"task"`.
+    ///
+    /// The override is used wherever that default would be, so `detail_level`,
+    /// `extra_fields`, and `opt_fields` still apply on top of it. The binary reads these
+    /// from `ASANA_<NAME>_FIELDS` (e.g. `ASANA_PROJECT_FIELDS`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `resource` has no configurable defaults or `fields` is empty.
+    pub fn with_default_fields(mut self, resource: &str, fields: &str) -> Result<Self, Error> {
+        let Some((_, default)) = CONFIGURABLE_FIELDS
+            .iter()
+            .find(|(name, _)| *name == resource)
+        else {
+            let names: Vec<&str> = CONFIGURABLE_FIELDS.iter().map(|(name, _)| *name).collect();
+            return Err(Error::Config(format!(
+                "no configurable default fields for {:?} (expected one of: {})",
+                resource,
+                names.join(", ")
+            )));
+        };
+        let fields: Vec<&str> = fields
+            .split(',')
+            .map(str::trim)
+            .filter(|f| !f.is_empty())
+            .collect();
+        if fields.is_empty() {
+            return Err(Error::Config(format!(
+                "default fields for {:?} must not be empty",
+                resource
+            )));
+        }
+        Arc::make_mut(&mut self.field_overrides).insert(default, fields.join(","));
+        Ok(self)
+    }
+
+    /// The curated default fields, or the override configured for them.
+    fn default_fields(&self, default: &'static str) -> &str {
+        self.field_overrides
+            .get(default)
+            .map(String::as_str)
+            .unwrap_or(default)
+    }
+
+    /// Register a named account that tool calls can select with their `account` parameter.
+    ///
+    /// Calls without `account` keep using the server's primary client and default workspace.
+    pub fn with_account(
+        mut self,
+        name: &str,
+        client: AsanaClient,
+        default_workspace_gid: Option<&str>,
+    ) -> Self {
+        let account = Account {
+            client,
+            default_workspace_gid: default_workspace_gid.map(str::to_string),
+            workspace_gids_by_name: Arc::default(),
+            detected_workspace_gid: Arc::default(),
+        };
+        Arc::make_mut(&mut self.accounts).insert(name.to_string(), account);
+        self
+    }
+
+    /// Clone the server to act as the named account.
+    fn for_account(&self, name: &str) -> Result<Self, McpError> {
+        let Some(account) = self.accounts.get(name) else {
+            let configured = if self.accounts.is_empty() {
+                "none (set ASANA_ACCOUNTS)".to_string()
+            } else {
+                self.accounts.keys().cloned().collect::<Vec<_>>().join(", ")
+            };
+            return Err(validation_error(&format!(
+                "Unknown account {:?}. Configured accounts: {}",
+                name, configured
+            )));
+        };
+        Ok(Self {
+            client: account.client.clone(),
+            default_workspace_gid: account.default_workspace_gid.clone(),
+            workspace_gids_by_name: account.workspace_gids_by_name.clone(),
+            detected_workspace_gid: account.detected_workspace_gid.clone(),
+            ..self.clone()
         })
     }
 
+    /// Set how many requests may run concurrently when expanding subtasks or portfolio items.
+    ///
+    /// Values below 1 are treated as 1 (fully sequential).
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Set the deepest level a recursive fetch (portfolio items, subtasks) will expand.
+    ///
+    /// Unlimited (`-1`) or deeper requests stop here, and the items left unexpanded are
+    /// marked `"truncated": "depth limit reached"`.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Set how many expansion requests a single recursive fetch may make.
+    ///
+    /// Once spent, the fetch returns what it has, marking the items left unexpanded
+    /// `"truncated": "request budget exhausted"`.
+    pub fn with_request_budget(mut self, requests: usize) -> Self {
+        self.request_budget = requests;
+        self
+    }
+
+    /// Start a recursive fetch to `depth` levels (`None` = unlimited) under this server's limits.
+    fn traversal(&self, depth: Option<usize>) -> Traversal {
+        Traversal::new(depth, self.max_depth, self.request_budget)
+    }
+
+    /// Set how long `wait_for_job` polls an instantiation or duplication job before giving up.
+    pub fn with_job_timeout(mut self, timeout: Duration) -> Self {
+        self.job_timeout = timeout;
+        self
+    }
+
     /// Create a server with a custom client (for testing).
     #[cfg(test)]
     pub(crate) fn with_client(client: AsanaClient) -> Self {
         Self {
             client,
             default_workspace_gid: None,
+            workspace_gids_by_name: Arc::default(),
+            detected_workspace_gid: Arc::default(),
+            accounts: Arc::default(),
+            field_overrides: Arc::default(),
+            concurrency: DEFAULT_CONCURRENCY,
+            max_depth: DEFAULT_MAX_DEPTH,
+            request_budget: DEFAULT_REQUEST_BUDGET,
+            job_timeout: DEFAULT_JOB_TIMEOUT,
             tool_router: Self::tool_router(),
         }
     }
@@ -64,15 +286,145 @@ impl AsanaServer {
         self
     }
 
+    /// Run a mutating tool call against a dry-run client and report the writes it attempted.
+    ///
+    /// Errors raised before the first write (e.g. parameter validation) are returned as
+    /// usual; the error that stops the first write is replaced by the planned requests.
+    async fn dry_run<F, Fut>(&self, call: F) -> Result<CallToolResult, McpError>
+    where
+        F: FnOnce(Self) -> Fut,
+        Fut: std::future::Future<Output = Result<CallToolResult, McpError>>,
+    {
+        let client = self.client.clone().with_dry_run();
+        let server = Self {
+            client: client.clone(),
+            ..self.clone()
+        };
+        let outcome = call(server).await;
+
+        let requests = client.planned_requests();
+        if requests.is_empty() {
+            return outcome;
+        }
+        json_response(&DryRunResponse {
+            dry_run: true,
+            requests,
+        })
+    }
+
     /// Resolve workspace GID from provided value or default.
-    fn resolve_workspace_gid(&self, provided: Option<&str>) -> Result<String, McpError> {
-        match provided.filter(|s| !s.is_empty()) {
-            Some(gid) => Ok(gid.to_string()),
-            None => self.default_workspace_gid.clone().ok_or_else(|| {
-                validation_error(
-                    "workspace_gid is required (or set ASANA_DEFAULT_WORKSPACE env var)",
-                )
-            }),
+    ///
+    /// Values that aren't numeric GIDs are treated as workspace names and matched
+    /// (case-insensitively) against `/workspaces`. Resolved names are cached.
+    /// Without a value or configured default, the user's only workspace is used.
+    async fn resolve_workspace_gid(&self, provided: Option<&str>) -> Result<String, McpError> {
+        let workspace = match (
+            provided.filter(|s| !s.is_empty()),
+            self.default_workspace_gid.as_deref(),
+        ) {
+            (Some(workspace), _) | (None, Some(workspace)) => workspace.to_string(),
+            (None, None) => return self.detect_default_workspace().await,
+        };
+        if is_gid(&workspace) {
+            return Ok(workspace);
+        }
+        self.resolve_workspace_name(&workspace).await
+    }
+
+    /// Look up the GID of the current user's task list (My Tasks) in a workspace.
+    async fn user_task_list_gid(&self, workspace_gid: &str) -> Result<String, McpError> {
+        let task_list: Resource = self
+            .client
+            .get(
+                "/users/me/user_task_list",
+                &[("workspace", workspace_gid), ("opt_fields", "gid")],
+            )
+            .await
+            .map_err(|e| error_to_mcp("Failed to get user task list", e))?;
+        Ok(task_list.gid)
+    }
+
+    /// Use the user's only workspace as the default, caching it after the first lookup.
+    ///
+    /// Errors with the available workspaces when there is more than one to choose from.
+    async fn detect_default_workspace(&self) -> Result<String, McpError> {
+        if let Some(gid) = self.detected_workspace_gid.lock().unwrap().clone() {
+            return Ok(gid);
+        }
+
+        let workspaces: Vec<Resource> = self
+            .client
+            .get_all("/workspaces", &[("opt_fields", "gid,name")])
+            .await
+            .map_err(|e| error_to_mcp("Failed to detect default workspace", e))?;
+
+        if let [workspace] = workspaces.as_slice() {
+            *self.detected_workspace_gid.lock().unwrap() = Some(workspace.gid.clone());
+            return Ok(workspace.gid.clone());
+        }
+
+        let mut message =
+            "workspace_gid is required (or set ASANA_DEFAULT_WORKSPACE env var)".to_string();
+        if !workspaces.is_empty() {
+            let available: Vec<String> = workspaces
+                .iter()
+                .map(|w| {
+                    let name = w.fields.get("name").and_then(|n| n.as_str());
+                    format!("{} ({})", name.unwrap_or_default(), w.gid)
+                })
+                .collect();
+            message.push_str(&format!(". Available workspaces: {}", available.join(", ")));
+        }
+        Err(validation_error(&message))
+    }
+
+    /// Look up a workspace GID by name, using the cache when possible.
+    async fn resolve_workspace_name(&self, name: &str) -> Result<String, McpError> {
+        let key = name.to_lowercase();
+        if let Some(gid) = self.workspace_gids_by_name.lock().unwrap().get(&key) {
+            return Ok(gid.clone());
+        }
+
+        let workspaces: Vec<Resource> = self
+            .client
+            .get_all("/workspaces", &[("opt_fields", "gid,name")])
+            .await
+            .map_err(|e| error_to_mcp("Failed to resolve workspace name", e))?;
+        let workspace_name = |w: &Resource| {
+            w.fields
+                .get("name")
+                .and_then(|n| n.as_str())
+                .map(str::to_string)
+        };
+        let matches: Vec<&Resource> = workspaces
+            .iter()
+            .filter(|w| workspace_name(w).is_some_and(|n| n.to_lowercase() == key))
+            .collect();
+
+        match matches.as_slice() {
+            [workspace] => {
+                self.workspace_gids_by_name
+                    .lock()
+                    .unwrap()
+                    .insert(key, workspace.gid.clone());
+                Ok(workspace.gid.clone())
+            }
+            [] => {
+                let available: Vec<String> = workspaces.iter().filter_map(workspace_name).collect();
+                Err(validation_error(&format!(
+                    "No workspace named '{}'. Available workspaces: {}",
+                    name,
+                    available.join(", ")
+                )))
+            }
+            _ => {
+                let gids: Vec<&str> = matches.iter().map(|w| w.gid.as_str()).collect();
+                Err(validation_error(&format!(
+                    "Workspace name '{}' is ambiguous; use one of these GIDs instead: {}",
+                    name,
+                    gids.join(", ")
+                )))
+            }
         }
     }
 
@@ -80,11 +432,20 @@ impl AsanaServer {
     #[tool(description = "List all Asana workspaces accessible to the authenticated user")]
     async fn asana_workspaces(
         &self,
-        _params: Parameters<WorkspacesParams>,
+        params: Parameters<WorkspacesParams>,
     ) -> Result<CallToolResult, McpError> {
+        if let Some(account) = params.0.account {
+            let server = self.for_account(&account)?;
+            let params = Parameters(WorkspacesParams { account: None });
+            return Box::pin(server.asana_workspaces(params)).await;
+        }
+
         let workspaces: Vec<Resource> = self
             .client
-            .get_all("/workspaces", &[("opt_fields", WORKSPACE_FIELDS)])
+            .get_all(
+                "/workspaces",
+                &[("opt_fields", self.default_fields(WORKSPACE_FIELDS))],
+            )
             .await
             .map_err(|e| error_to_mcp("Failed to list workspaces", e))?;
 
@@ -94,54 +455,133 @@ impl AsanaServer {
     /// Universal get tool for fetching Asana resources.
     #[tool(description = "Get any Asana resource by type and GID. Supports:\n\
             - project: Get a project (gid = project GID)\n\
+            - project_status: Get a project's current status (status_type such as on_track/at_risk/off_track, \
+            and title of the latest status update) without listing all updates (gid = project GID)\n\
+            - project_task_counts: Count a project's tasks and milestones (total, incomplete, completed) in one \
+            cheap request, e.g. to decide whether to fetch all tasks or narrow first (gid = project GID)\n\
             - portfolio: Get a portfolio with nested items (gid = portfolio GID, use depth to control recursion)\n\
             - task: Get a task with context (gid = task GID, use include_* flags)\n\
-            - my_tasks: Get tasks assigned to current user (gid = workspace GID or empty for default)\n\
+            - my_tasks: Get tasks assigned to current user, with their My Tasks section (gid = workspace GID or empty for default)\n\
+            - my_task_sections: List the sections of the current user's My Tasks (gid = workspace GID or empty for default)\n\
             - workspace_favorites: Get user's favorites (gid = workspace GID or empty for default)\n\
             - workspace_projects: List all projects in workspace (gid = workspace GID or empty for default)\n\
             - project_tasks: Get all tasks from a project/portfolio (gid = project/portfolio GID, use subtask_depth)\n\
             - task_subtasks: Get subtasks of a task (gid = task GID)\n\
             - task_comments: Get comments on a task (gid = task GID)\n\
+            - task_stories: Get a task's full activity log, including assignment, due date, and \
+            completion changes as well as comments (gid = task GID)\n\
+            - task_dependencies: List the tasks a task depends on (gid = task GID). Cheaper than fetching the task with context.\n\
+            - task_dependents: List the tasks that depend on a task (gid = task GID)\n\
             - status_update: Get a single status update by its GID (gid = the status update's own GID)\n\
             - status_updates: List all status updates posted on a project, portfolio, or goal (gid = the parent project/portfolio/goal GID)\n\
             - all_workspaces: List all workspaces (gid is ignored)\n\
-            - workspace: Get a single workspace (gid = workspace GID)\n\
+            - workspace: Get a single workspace (gid = workspace GID or empty for default)\n\
             - workspace_templates: List templates (gid = team GID for team templates, or empty for all)\n\
             - project_template: Get a single template (gid = template GID)\n\
+            - project_template_variables: List a template's requested_dates and requested_roles as {gid, name, value} \
+            entries to fill in for project_from_template (gid = template GID)\n\
+            - workspace_task_templates: List task templates (gid = project GID; Asana scopes task templates to projects)\n\
+            - task_template: Get a single task template (gid = task template GID)\n\
+            - job: Get an instantiation or duplication job's status (gid = job GID), including new_project/new_task\n\
             - project_sections: List sections in a project (gid = project GID)\n\
             - section: Get a single section (gid = section GID)\n\
             - workspace_tags: List tags (gid = workspace GID or empty for default)\n\
             - tag: Get a single tag (gid = tag GID)\n\
             - me: Get current authenticated user (gid ignored)\n\
+            - whoami: Get the current user, their workspaces, and the default workspace GID in one call; \
+            a good first call to orient a session (gid ignored)\n\
             - user: Get a user (gid = user GID)\n\
             - workspace_users: List users (gid = workspace GID or empty for default)\n\
             - team: Get a team (gid = team GID)\n\
             - workspace_teams: List teams (gid = workspace GID or empty for default)\n\
             - team_users: List users in a team (gid = team GID)\n\
+            - project_memberships: List project members with their access_level (gid = project GID)\n\
+            - portfolio_memberships: List portfolio members with their access_level (gid = portfolio GID)\n\
+            - team_memberships: List team members with is_admin/is_guest (gid = team GID)\n\
             - project_custom_fields: Get custom fields for a project (gid = project GID)\n\
+            - custom_field: Get a custom field definition with its enum options (gid = custom field GID)\n\
+            - workspace_custom_fields: List custom field definitions in a workspace (gid = workspace GID or empty for default)\n\
             - project_brief: Get project brief by brief GID. This is the 'Key Resources' on the Overview tab (NOT the Note tab).\n\
-            - project_project_brief: Get project's brief via project GID. Returns the brief embedded in project, including its GID.\n\n\
-            For workspace-based operations, empty gid uses ASANA_DEFAULT_WORKSPACE env var.\n\
-            Depth parameters: -1 = unlimited, 0 = none, N = N levels\n\n\
-            opt_fields: Override default fields returned. Curated defaults provided per resource type.")]
+            - project_project_brief: Get project's brief via project GID. Returns the brief embedded in project, including its GID.\n\
+            - goal: Get a goal (gid = goal GID)\n\
+            - workspace_goals: List goals in a workspace (gid = workspace GID or empty for default). \
+            time_periods filters to goals in those periods, by GID or display name (e.g. [\"Q1 FY25\"])\n\
+            - time_periods: List goal time periods with display_name, period, start_on, end_on (gid = workspace GID or empty for default)\n\
+            - goal_relationships: List supporting work (subgoals, projects, portfolios) for a goal (gid = goal GID)\n\
+            - task_attachments: List attachments (gid = task, project, or project brief GID)\n\
+            - attachment: Get a single attachment (gid = attachment GID). Includes download_url for fetching the file.\n\
+            - webhooks: List webhooks in a workspace (gid = workspace GID or empty for default)\n\
+            - webhook: Get a webhook (gid = webhook GID), including its target and active status\n\
+            - audit_log: List audit log events with their actor, resource, and context (gid = workspace GID or empty \
+            for default). Filter with start_at, end_at, event_type, actor_gid. Returns at most 100 events unless \
+            limit is set. Requires a service account token from an Enterprise organization admin.\n\n\
+            For workspace-based operations, empty gid uses ASANA_DEFAULT_WORKSPACE env var, and a workspace name may be given instead of its GID.\n\
+            limit: Cap results for workspace-wide lists; when more exist the response is {data, has_more: true}.\n\
+            with_metadata: For workspace-wide lists, return {count, truncated, data} instead of a bare array.\n\
+            format: json (default), markdown for a compact summary of a task or project, \
+            or csv for workspace-wide lists, project_tasks, and task_subtasks.\n\
+            summarize: For project_tasks, return counts (total, completed, incomplete, overdue, by_assignee) instead of tasks.\n\
+            latest_only: For status_updates, return only the most recent update (by created_at).\n\
+            group_by_section: For my_tasks, return tasks grouped by My Tasks section as [{section, tasks}].\n\
+            max_text_len: Long notes/html_notes/text/html_text fields are cut to this many characters (default 10000, 0 = no limit).\n\
+            flatten_custom_fields: Return custom_fields as a {field name: value} map (enum = option name, people = list of names).\n\
+            comment_limit: For task, task_comments, and task_stories, keep only the N most recent stories \
+            (default 50, 0 = no limit); has_more (or has_more_comments on a task) marks that older ones exist.\n\
+            Depth parameters: -1 = unlimited, 0 = none, N = N levels. Recursion is capped by the server; \
+            items left unexpanded carry a \"truncated\" reason.\n\n\
+            opt_fields: Override default fields returned. Curated defaults provided per resource type.\n\
+            opt_expand: Return these nested objects in full (e.g. [\"assignee\"]) instead of {gid, name} references.")]
     async fn asana_get(&self, params: Parameters<GetParams>) -> Result<CallToolResult, McpError> {
-        let p = params.0;
+        let mut p = params.0;
+        if let Some(account) = p.account.take() {
+            let server = self.for_account(&account)?;
+            return Box::pin(server.asana_get(Parameters(p))).await;
+        }
+        let max_text_len = p.max_text_len.unwrap_or(DEFAULT_MAX_TEXT_LEN);
+        let flatten = p.flatten_custom_fields;
+        let result = match p.opt_expand.take() {
+            Some(expand) => {
+                let expand: Vec<&str> = expand.iter().map(String::as_str).collect();
+                let server = Self {
+                    client: self.client.clone().with_opt_expand(&expand),
+                    ..self.clone()
+                };
+                server.get_resource(p).await?
+            }
+            None => self.get_resource(p).await?,
+        };
+        let result = if flatten {
+            flatten_custom_fields(result)
+        } else {
+            result
+        };
+        Ok(truncate_text_fields(result, max_text_len))
+    }
 
+    /// Fetch the resource described by `asana_get` parameters.
+    async fn get_resource(&self, p: GetParams) -> Result<CallToolResult, McpError> {
         match p.resource_type {
             ResourceType::Project => {
                 let gid = require_gid(&p.gid, "project")?;
-                let fields = resolve_fields_from_get_params(&p, PROJECT_FIELDS);
+                if p.format == OutputFormat::Csv {
+                    return Err(validation_error(CSV_LIST_ONLY));
+                }
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(PROJECT_FIELDS));
                 let project: Resource = self
                     .client
                     .get(&format!("/projects/{}", gid), &[("opt_fields", &fields)])
                     .await
                     .map_err(|e| error_to_mcp("Failed to get project", e))?;
-                json_response(&project)
+                match p.format {
+                    OutputFormat::Json | OutputFormat::Csv => json_response(&project),
+                    OutputFormat::Markdown => text_response(format::project_markdown(&project)),
+                }
             }
 
             ResourceType::Portfolio => {
                 let gid = require_gid(&p.gid, "portfolio")?;
-                let depth = depth_to_option(p.depth.unwrap_or(0));
+                let depth = checked_depth(p.depth.unwrap_or(0), "depth")?;
                 let portfolio = self
                     .get_portfolio_recursive(&gid, depth)
                     .await
@@ -151,21 +591,28 @@ impl AsanaServer {
 
             ResourceType::Task => {
                 let gid = require_gid(&p.gid, "task")?;
+                if p.format == OutputFormat::Csv {
+                    return Err(validation_error(CSV_LIST_ONLY));
+                }
                 let task = self
                     .get_task_with_context(
                         &gid,
                         p.include_subtasks.unwrap_or(true),
                         p.include_dependencies.unwrap_or(true),
                         p.include_comments.unwrap_or(true),
+                        comment_limit(p.comment_limit),
                     )
                     .await
                     .map_err(|e| error_to_mcp("Failed to get task", e))?;
-                json_response(&task)
+                match p.format {
+                    OutputFormat::Json | OutputFormat::Csv => json_response(&task),
+                    OutputFormat::Markdown => text_response(format::task_markdown(&task)),
+                }
             }
 
             ResourceType::WorkspaceFavorites => {
-                let workspace_gid = self.resolve_workspace_gid(p.gid.as_deref())?;
-                let depth = depth_to_option(p.depth.unwrap_or(0));
+                let workspace_gid = self.resolve_workspace_gid(p.gid.as_deref()).await?;
+                let traversal = self.traversal(checked_depth(p.depth.unwrap_or(0), "depth")?);
 
                 let mut projects = Vec::new();
                 let mut portfolios = Vec::new();
@@ -185,15 +632,10 @@ impl AsanaServer {
                     .await
                     .map_err(|e| error_to_mcp("Failed to get favorite projects", e))?;
 
-                for item in fav_projects {
-                    match self
-                        .client
-                        .get::<Resource>(
-                            &format!("/projects/{}", item.gid),
-                            &[("opt_fields", PROJECT_FIELDS)],
-                        )
-                        .await
-                    {
+                let project_results = self.batch_get_projects(fav_projects).await;
+
+                for (item, result) in project_results {
+                    match result {
                         Ok(project) => projects.push(project),
                         Err(e) => errors.push(FavoriteError {
                             item,
@@ -216,8 +658,21 @@ impl AsanaServer {
                     .await
                     .map_err(|e| error_to_mcp("Failed to get favorite portfolios", e))?;
 
-                for item in fav_portfolios {
-                    match self.get_portfolio_recursive(&item.gid, depth).await {
+                // Hydrate portfolios concurrently; `buffered` keeps them in favorites order
+                let traversal = &traversal;
+                let portfolio_results: Vec<_> = stream::iter(fav_portfolios)
+                    .map(|item| async move {
+                        let result = self
+                            .fetch_portfolio_with_depth(&item.gid, traversal, &[], 0)
+                            .await;
+                        (item, result)
+                    })
+                    .buffered(self.concurrency)
+                    .collect()
+                    .await;
+
+                for (item, result) in portfolio_results {
+                    match result {
                         Ok(portfolio) => portfolios.push(portfolio),
                         Err(e) => errors.push(FavoriteError {
                             item,
@@ -235,22 +690,23 @@ impl AsanaServer {
 
             ResourceType::ProjectTasks => {
                 let gid = require_gid(&p.gid, "project_tasks")?;
-                let subtask_depth = p
-                    .subtask_depth
-                    .map(|d| if d < 0 { None } else { Some(d) })
-                    .unwrap_or(Some(0));
-                let portfolio_depth = Some(p.depth.unwrap_or(0));
+                let subtask_depth = checked_depth(p.subtask_depth.unwrap_or(0), "subtask_depth")?;
+                let portfolio_depth = checked_depth(p.depth.unwrap_or(0), "depth")?;
 
                 let tasks = self
                     .get_tasks_recursive(&gid, subtask_depth, portfolio_depth)
                     .await
                     .map_err(|e| error_to_mcp("Failed to get tasks", e))?;
-                json_response(&tasks)
+                if p.summarize {
+                    return json_response(&summarize_tasks(&tasks, Utc::now()));
+                }
+                list_response(&tasks, self.default_fields(RECURSIVE_TASK_FIELDS), p.format)
             }
 
             ResourceType::TaskSubtasks => {
                 let gid = require_gid(&p.gid, "task_subtasks")?;
-                let fields = resolve_fields_from_get_params(&p, SUBTASK_FIELDS);
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(SUBTASK_FIELDS));
                 let subtasks: Vec<Resource> = self
                     .client
                     .get_all(
@@ -259,27 +715,63 @@ impl AsanaServer {
                     )
                     .await
                     .map_err(|e| error_to_mcp("Failed to get subtasks", e))?;
-                json_response(&subtasks)
+                list_response(&subtasks, &fields, p.format)
             }
 
             ResourceType::TaskComments => {
                 let gid = require_gid(&p.gid, "task_comments")?;
-                let fields = resolve_fields_from_get_params(&p, STORY_FIELDS);
-                let stories: Vec<Story> = self
+                let fields = resolve_fields_from_get_params(&p, self.default_fields(STORY_FIELDS));
+                let comments = self
+                    .recent_stories(&gid, &fields, true, comment_limit(p.comment_limit))
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to get comments", e))?;
+                recent_stories_response(&comments)
+            }
+
+            ResourceType::TaskStories => {
+                let gid = require_gid(&p.gid, "task_stories")?;
+                let fields = resolve_fields_from_get_params(&p, self.default_fields(STORY_FIELDS));
+                let stories = self
+                    .recent_stories(&gid, &fields, false, comment_limit(p.comment_limit))
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to get stories", e))?;
+                recent_stories_response(&stories)
+            }
+
+            ResourceType::TaskDependencies => {
+                let gid = require_gid(&p.gid, "task_dependencies")?;
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(DEPENDENCY_FIELDS));
+                let dependencies: Vec<TaskDependency> = self
                     .client
                     .get_all(
-                        &format!("/tasks/{}/stories", gid),
+                        &format!("/tasks/{}/dependencies", gid),
                         &[("opt_fields", &fields)],
                     )
                     .await
-                    .map_err(|e| error_to_mcp("Failed to get comments", e))?;
-                let comments: Vec<_> = stories.into_iter().filter(|s| s.is_comment()).collect();
-                json_response(&comments)
+                    .map_err(|e| error_to_mcp("Failed to get dependencies", e))?;
+                json_response(&dependencies)
+            }
+
+            ResourceType::TaskDependents => {
+                let gid = require_gid(&p.gid, "task_dependents")?;
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(DEPENDENCY_FIELDS));
+                let dependents: Vec<TaskDependency> = self
+                    .client
+                    .get_all(
+                        &format!("/tasks/{}/dependents", gid),
+                        &[("opt_fields", &fields)],
+                    )
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to get dependents", e))?;
+                json_response(&dependents)
             }
 
             ResourceType::StatusUpdate => {
                 let gid = require_gid(&p.gid, "status_update")?;
-                let fields = resolve_fields_from_get_params(&p, STATUS_UPDATE_FIELDS);
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(STATUS_UPDATE_FIELDS));
                 let status: Resource = self
                     .client
                     .get(
@@ -293,8 +785,9 @@ impl AsanaServer {
 
             ResourceType::StatusUpdates => {
                 let gid = require_gid(&p.gid, "status_updates")?;
-                let fields = resolve_fields_from_get_params(&p, STATUS_UPDATE_FIELDS);
-                let updates: Vec<Resource> = self
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(STATUS_UPDATE_FIELDS));
+                let mut updates: Vec<Resource> = self
                     .client
                     .get_all(
                         "/status_updates",
@@ -302,11 +795,15 @@ impl AsanaServer {
                     )
                     .await
                     .map_err(|e| error_to_mcp("Failed to get status updates", e))?;
+                if p.latest_only {
+                    updates = latest_status_update(updates).into_iter().collect();
+                }
                 json_response(&updates)
             }
 
             ResourceType::AllWorkspaces => {
-                let fields = resolve_fields_from_get_params(&p, WORKSPACE_FIELDS);
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(WORKSPACE_FIELDS));
                 let workspaces: Vec<Resource> = self
                     .client
                     .get_all("/workspaces", &[("opt_fields", &fields)])
@@ -316,8 +813,9 @@ impl AsanaServer {
             }
 
             ResourceType::Workspace => {
-                let gid = require_gid(&p.gid, "workspace")?;
-                let fields = resolve_fields_from_get_params(&p, WORKSPACE_FIELDS);
+                let gid = self.resolve_workspace_gid(p.gid.as_deref()).await?;
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(WORKSPACE_FIELDS));
                 let workspace: Resource = self
                     .client
                     .get(&format!("/workspaces/{}", gid), &[("opt_fields", &fields)])
@@ -329,7 +827,8 @@ impl AsanaServer {
             ResourceType::WorkspaceTemplates => {
                 // Note: Asana's API uses /project_templates (not workspace-scoped)
                 // If team_gid is provided via gid, use team endpoint; otherwise list all
-                let fields = resolve_fields_from_get_params(&p, TEMPLATE_FIELDS);
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(TEMPLATE_FIELDS));
                 let templates: Vec<Resource> =
                     if let Some(team_gid) = p.gid.as_ref().filter(|s| !s.is_empty()) {
                         // Treat gid as team_gid for team-scoped templates
@@ -352,7 +851,8 @@ impl AsanaServer {
 
             ResourceType::ProjectTemplate => {
                 let gid = require_gid(&p.gid, "project_template")?;
-                let fields = resolve_fields_from_get_params(&p, TEMPLATE_FIELDS);
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(TEMPLATE_FIELDS));
                 let template: Resource = self
                     .client
                     .get(
@@ -364,9 +864,65 @@ impl AsanaServer {
                 json_response(&template)
             }
 
+            ResourceType::ProjectTemplateVariables => {
+                let gid = require_gid(&p.gid, "project_template_variables (template GID)")?;
+                let variables: TemplateVariables = self
+                    .client
+                    .get(
+                        &format!("/project_templates/{}", gid),
+                        &[("opt_fields", TEMPLATE_VARIABLE_FIELDS)],
+                    )
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to get project template variables", e))?;
+                json_response(&variables)
+            }
+
+            ResourceType::WorkspaceTaskTemplates => {
+                // Note: Asana only lists task templates per project, not per workspace
+                let gid = require_gid(&p.gid, "workspace_task_templates (project GID)")?;
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(TASK_TEMPLATE_FIELDS));
+                let templates: Vec<Resource> = self
+                    .client
+                    .get_all(
+                        "/task_templates",
+                        &[("project", &gid), ("opt_fields", &fields)],
+                    )
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to list task templates", e))?;
+                json_response(&templates)
+            }
+
+            ResourceType::Job => {
+                let gid = require_gid(&p.gid, "job")?;
+                let fields = resolve_fields_from_get_params(&p, self.default_fields(JOB_FIELDS));
+                let job: Job = self
+                    .client
+                    .get(&format!("/jobs/{}", gid), &[("opt_fields", &fields)])
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to get job", e))?;
+                json_response(&job)
+            }
+
+            ResourceType::TaskTemplate => {
+                let gid = require_gid(&p.gid, "task_template")?;
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(TASK_TEMPLATE_FIELDS));
+                let template: Resource = self
+                    .client
+                    .get(
+                        &format!("/task_templates/{}", gid),
+                        &[("opt_fields", &fields)],
+                    )
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to get task template", e))?;
+                json_response(&template)
+            }
+
             ResourceType::ProjectSections => {
                 let gid = require_gid(&p.gid, "project_sections")?;
-                let fields = resolve_fields_from_get_params(&p, SECTION_FIELDS);
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(SECTION_FIELDS));
                 let sections: Vec<Resource> = self
                     .client
                     .get_all(
@@ -380,7 +936,8 @@ impl AsanaServer {
 
             ResourceType::Section => {
                 let gid = require_gid(&p.gid, "section")?;
-                let fields = resolve_fields_from_get_params(&p, SECTION_FIELDS);
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(SECTION_FIELDS));
                 let section: Resource = self
                     .client
                     .get(&format!("/sections/{}", gid), &[("opt_fields", &fields)])
@@ -390,22 +947,23 @@ impl AsanaServer {
             }
 
             ResourceType::WorkspaceTags => {
-                let workspace_gid = self.resolve_workspace_gid(p.gid.as_deref())?;
-                let fields = resolve_fields_from_get_params(&p, TAG_FIELDS);
-                let tags: Vec<Resource> = self
+                let workspace_gid = self.resolve_workspace_gid(p.gid.as_deref()).await?;
+                let fields = resolve_fields_from_get_params(&p, self.default_fields(TAG_FIELDS));
+                let tags = self
                     .client
-                    .get_all(
+                    .get_all_limited::<Resource>(
                         &format!("/workspaces/{}/tags", workspace_gid),
                         &[("opt_fields", &fields)],
+                        p.limit,
                     )
                     .await
                     .map_err(|e| error_to_mcp("Failed to list tags", e))?;
-                json_response(&tags)
+                limited_list_response(&tags, &fields, p.format, p.with_metadata)
             }
 
             ResourceType::Tag => {
                 let gid = require_gid(&p.gid, "tag")?;
-                let fields = resolve_fields_from_get_params(&p, TAG_FIELDS);
+                let fields = resolve_fields_from_get_params(&p, self.default_fields(TAG_FIELDS));
                 let tag: Resource = self
                     .client
                     .get(&format!("/tags/{}", gid), &[("opt_fields", &fields)])
@@ -415,46 +973,60 @@ impl AsanaServer {
             }
 
             ResourceType::MyTasks => {
-                let workspace_gid = self.resolve_workspace_gid(p.gid.as_deref())?;
-                let fields = resolve_fields_from_get_params(&p, RECURSIVE_TASK_FIELDS);
-                // First get the user's task list for this workspace
-                let task_list: Resource = self
+                let workspace_gid = self.resolve_workspace_gid(p.gid.as_deref()).await?;
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(MY_TASK_FIELDS));
+                let task_list_gid = self.user_task_list_gid(&workspace_gid).await?;
+                let tasks: Vec<Resource> = self
                     .client
-                    .get(
-                        "/users/me/user_task_list",
-                        &[("workspace", workspace_gid.as_str()), ("opt_fields", "gid")],
+                    .get_all(
+                        &format!("/user_task_lists/{}/tasks", task_list_gid),
+                        &[("opt_fields", &fields)],
                     )
                     .await
-                    .map_err(|e| error_to_mcp("Failed to get user task list", e))?;
+                    .map_err(|e| error_to_mcp("Failed to get tasks", e))?;
+                if p.group_by_section {
+                    json_response(&group_by_assignee_section(tasks))
+                } else {
+                    json_response(&tasks)
+                }
+            }
 
-                // Then get tasks from that list
-                let tasks: Vec<Resource> = self
+            ResourceType::MyTaskSections => {
+                let workspace_gid = self.resolve_workspace_gid(p.gid.as_deref()).await?;
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(SECTION_FIELDS));
+                // My Tasks sections live on the user task list, which Asana addresses as a project
+                let task_list_gid = self.user_task_list_gid(&workspace_gid).await?;
+                let sections: Vec<Resource> = self
                     .client
                     .get_all(
-                        &format!("/user_task_lists/{}/tasks", task_list.gid),
+                        &format!("/projects/{}/sections", task_list_gid),
                         &[("opt_fields", &fields)],
                     )
                     .await
-                    .map_err(|e| error_to_mcp("Failed to get tasks", e))?;
-                json_response(&tasks)
+                    .map_err(|e| error_to_mcp("Failed to list My Tasks sections", e))?;
+                json_response(&sections)
             }
 
             ResourceType::WorkspaceProjects => {
-                let workspace_gid = self.resolve_workspace_gid(p.gid.as_deref())?;
-                let fields = resolve_fields_from_get_params(&p, PROJECT_FIELDS);
-                let projects: Vec<Resource> = self
+                let workspace_gid = self.resolve_workspace_gid(p.gid.as_deref()).await?;
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(PROJECT_FIELDS));
+                let projects = self
                     .client
-                    .get_all(
+                    .get_all_limited::<Resource>(
                         &format!("/workspaces/{}/projects", workspace_gid),
                         &[("opt_fields", &fields)],
+                        p.limit,
                     )
                     .await
                     .map_err(|e| error_to_mcp("Failed to get projects", e))?;
-                json_response(&projects)
+                limited_list_response(&projects, &fields, p.format, p.with_metadata)
             }
 
             ResourceType::Me => {
-                let fields = resolve_fields_from_get_params(&p, USER_FIELDS);
+                let fields = resolve_fields_from_get_params(&p, self.default_fields(USER_FIELDS));
                 let user: Resource = self
                     .client
                     .get("/users/me", &[("opt_fields", &fields)])
@@ -463,9 +1035,62 @@ impl AsanaServer {
                 json_response(&user)
             }
 
+            ResourceType::WhoAmI => {
+                let fields = resolve_fields_from_get_params(&p, self.default_fields(USER_FIELDS));
+                let user_query = [("opt_fields", fields.as_str())];
+                let workspace_query = [("opt_fields", self.default_fields(WORKSPACE_FIELDS))];
+                let (user, workspaces) = futures::try_join!(
+                    self.client.get::<Resource>("/users/me", &user_query),
+                    self.client
+                        .get_all::<Resource>("/workspaces", &workspace_query),
+                )
+                .map_err(|e| error_to_mcp("Failed to get current user", e))?;
+                // Match a configured name against the list in hand, so a name that doesn't
+                // match is reported rather than failing the call meant to show the real names
+                let mut warnings = Vec::new();
+                let default_workspace_gid = match (
+                    self.default_workspace_gid.as_deref(),
+                    &workspaces[..],
+                ) {
+                    (Some(default), _) if is_gid(default) => Some(default.to_string()),
+                    (Some(default), _) => {
+                        let key = default.to_lowercase();
+                        let matches: Vec<&Resource> = workspaces
+                            .iter()
+                            .filter(|w| {
+                                w.fields
+                                    .get("name")
+                                    .and_then(|n| n.as_str())
+                                    .is_some_and(|n| n.to_lowercase() == key)
+                            })
+                            .collect();
+                        match matches.as_slice() {
+                            [workspace] => Some(workspace.gid.clone()),
+                            _ => {
+                                warnings.push(format!(
+                                    "default workspace {:?} does not match exactly one workspace name",
+                                    default
+                                ));
+                                None
+                            }
+                        }
+                    }
+                    (None, [workspace]) => Some(workspace.gid.clone()),
+                    (None, _) => None,
+                };
+                warnings_response(
+                    &WhoAmI {
+                        user,
+                        workspaces,
+                        default_workspace_gid,
+                    },
+                    &warnings,
+                )
+            }
+
             ResourceType::User => {
                 let gid = require_gid(&p.gid, "user")?;
-                let fields = resolve_fields_from_get_params(&p, USER_FIELDS);
+                let fields = resolve_fields_from_get_params(&p, self.default_fields(USER_FIELDS));
                 let user: Resource = self
                     .client
                     .get(&format!("/users/{}", gid), &[("opt_fields", &fields)])
@@ -475,22 +1100,23 @@ impl AsanaServer {
             }
 
             ResourceType::WorkspaceUsers => {
-                let workspace_gid = self.resolve_workspace_gid(p.gid.as_deref())?;
-                let fields = resolve_fields_from_get_params(&p, USER_FIELDS);
-                let users: Vec<Resource> = self
+                let workspace_gid = self.resolve_workspace_gid(p.gid.as_deref()).await?;
+                let fields = resolve_fields_from_get_params(&p, self.default_fields(USER_FIELDS));
+                let users = self
                     .client
-                    .get_all(
+                    .get_all_limited::<Resource>(
                         &format!("/workspaces/{}/users", workspace_gid),
                         &[("opt_fields", &fields)],
+                        p.limit,
                     )
                     .await
                     .map_err(|e| error_to_mcp("Failed to get users", e))?;
-                json_response(&users)
+                limited_list_response(&users, &fields, p.format, p.with_metadata)
             }
 
             ResourceType::Team => {
                 let gid = require_gid(&p.gid, "team")?;
-                let fields = resolve_fields_from_get_params(&p, TEAM_FIELDS);
+                let fields = resolve_fields_from_get_params(&p, self.default_fields(TEAM_FIELDS));
                 let team: Resource = self
                     .client
                     .get(&format!("/teams/{}", gid), &[("opt_fields", &fields)])
@@ -500,22 +1126,23 @@ impl AsanaServer {
             }
 
             ResourceType::WorkspaceTeams => {
-                let workspace_gid = self.resolve_workspace_gid(p.gid.as_deref())?;
-                let fields = resolve_fields_from_get_params(&p, TEAM_FIELDS);
-                let teams: Vec<Resource> = self
+                let workspace_gid = self.resolve_workspace_gid(p.gid.as_deref()).await?;
+                let fields = resolve_fields_from_get_params(&p, self.default_fields(TEAM_FIELDS));
+                let teams = self
                     .client
-                    .get_all(
+                    .get_all_limited::<Resource>(
                         &format!("/workspaces/{}/teams", workspace_gid),
                         &[("opt_fields", &fields)],
+                        p.limit,
                     )
                     .await
                     .map_err(|e| error_to_mcp("Failed to get teams", e))?;
-                json_response(&teams)
+                limited_list_response(&teams, &fields, p.format, p.with_metadata)
             }
 
             ResourceType::TeamUsers => {
                 let gid = require_gid(&p.gid, "team_users")?;
-                let fields = resolve_fields_from_get_params(&p, USER_FIELDS);
+                let fields = resolve_fields_from_get_params(&p, self.default_fields(USER_FIELDS));
                 let users: Vec<Resource> = self
                     .client
                     .get_all(&format!("/teams/{}/users", gid), &[("opt_fields", &fields)])
@@ -524,9 +1151,57 @@ impl AsanaServer {
                 json_response(&users)
             }
 
+            ResourceType::ProjectMemberships => {
+                let gid = require_gid(&p.gid, "project_memberships (project GID)")?;
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(MEMBERSHIP_FIELDS));
+                let memberships: Vec<Resource> = self
+                    .client
+                    .get_all(
+                        &format!("/projects/{}/project_memberships", gid),
+                        &[("opt_fields", &fields)],
+                    )
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to get project memberships", e))?;
+                json_response(&memberships)
+            }
+
+            ResourceType::PortfolioMemberships => {
+                let gid = require_gid(&p.gid, "portfolio_memberships (portfolio GID)")?;
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(MEMBERSHIP_FIELDS));
+                let memberships: Vec<Resource> = self
+                    .client
+                    .get_all(
+                        "/portfolio_memberships",
+                        &[("portfolio", &gid), ("opt_fields", &fields)],
+                    )
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to get portfolio memberships", e))?;
+                json_response(&memberships)
+            }
+
+            ResourceType::TeamMemberships => {
+                let gid = require_gid(&p.gid, "team_memberships (team GID)")?;
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(TEAM_MEMBERSHIP_FIELDS));
+                let memberships: Vec<Resource> = self
+                    .client
+                    .get_all(
+                        &format!("/teams/{}/team_memberships", gid),
+                        &[("opt_fields", &fields)],
+                    )
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to get team memberships", e))?;
+                json_response(&memberships)
+            }
+
             ResourceType::ProjectCustomFields => {
                 let gid = require_gid(&p.gid, "project_custom_fields")?;
-                let fields = resolve_fields_from_get_params(&p, CUSTOM_FIELD_SETTINGS_FIELDS);
+                let fields = resolve_fields_from_get_params(
+                    &p,
+                    self.default_fields(CUSTOM_FIELD_SETTINGS_FIELDS),
+                );
                 let settings: Vec<Resource> = self
                     .client
                     .get_all(
@@ -538,9 +1213,41 @@ impl AsanaServer {
                 json_response(&settings)
             }
 
+            ResourceType::CustomField => {
+                let gid = require_gid(&p.gid, "custom_field")?;
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(CUSTOM_FIELD_FIELDS));
+                let custom_field: Resource = self
+                    .client
+                    .get(
+                        &format!("/custom_fields/{}", gid),
+                        &[("opt_fields", &fields)],
+                    )
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to get custom field", e))?;
+                json_response(&custom_field)
+            }
+
+            ResourceType::WorkspaceCustomFields => {
+                let workspace_gid = self.resolve_workspace_gid(p.gid.as_deref()).await?;
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(CUSTOM_FIELD_FIELDS));
+                let custom_fields = self
+                    .client
+                    .get_all_limited::<Resource>(
+                        &format!("/workspaces/{}/custom_fields", workspace_gid),
+                        &[("opt_fields", &fields)],
+                        p.limit,
+                    )
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to list custom fields", e))?;
+                limited_list_response(&custom_fields, &fields, p.format, p.with_metadata)
+            }
+
             ResourceType::ProjectBrief => {
                 let gid = require_gid(&p.gid, "project_brief (brief GID)")?;
-                let fields = resolve_fields_from_get_params(&p, PROJECT_BRIEF_FIELDS);
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(PROJECT_BRIEF_FIELDS));
                 let brief: Resource = self
                     .client
                     .get(
@@ -552,6 +1259,38 @@ impl AsanaServer {
                 json_response(&brief)
             }
 
+            ResourceType::ProjectStatus => {
+                let gid = require_gid(&p.gid, "project_status (project GID)")?;
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(CURRENT_STATUS_FIELDS));
+                let project: Resource = self
+                    .client
+                    .get(&format!("/projects/{}", gid), &[("opt_fields", &fields)])
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to get project status", e))?;
+                json_response(&project)
+            }
+
+            ResourceType::ProjectTaskCounts => {
+                let gid = require_gid(&p.gid, "project_task_counts (project GID)")?;
+                // Only count fields are valid here, so detail_level doesn't apply
+                let fields = p
+                    .opt_fields
+                    .as_ref()
+                    .filter(|f| !f.is_empty())
+                    .map(|f| f.join(","))
+                    .unwrap_or_else(|| TASK_COUNT_FIELDS.to_string());
+                let counts: serde_json::Value = self
+                    .client
+                    .get(
+                        &format!("/projects/{}/task_counts", gid),
+                        &[("opt_fields", &fields)],
+                    )
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to get project task counts", e))?;
+                json_response(&counts)
+            }
+
             ResourceType::ProjectProjectBrief => {
                 // Fetch the project with project_brief as opt_field to discover the brief's GID
                 let gid = require_gid(&p.gid, "project_project_brief (project GID)")?;
@@ -578,32 +1317,247 @@ impl AsanaServer {
                     ))
                 }
             }
-        }
-    }
 
-    /// Create Asana resources.
-    #[tool(description = "Create a new Asana resource. Supports:\n\
-            - task: Create a task (workspace_gid or project_gid, uses default workspace if neither)\n\
-            - subtask: Create a subtask (task_gid = parent task)\n\
-            - project: Create a project (workspace_gid or team_gid required)\n\
-            - project_from_template: Instantiate from template (template_gid required)\n\
-            - portfolio: Create a portfolio (uses default workspace if workspace_gid not provided)\n\
+            ResourceType::Goal => {
+                let gid = require_gid(&p.gid, "goal")?;
+                let fields = resolve_fields_from_get_params(&p, self.default_fields(GOAL_FIELDS));
+                let goal: Resource = self
+                    .client
+                    .get(&format!("/goals/{}", gid), &[("opt_fields", &fields)])
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to get goal", e))?;
+                json_response(&goal)
+            }
+
+            ResourceType::WorkspaceGoals => {
+                let workspace_gid = self.resolve_workspace_gid(p.gid.as_deref()).await?;
+                let fields = resolve_fields_from_get_params(&p, self.default_fields(GOAL_FIELDS));
+                let time_periods = match p.time_periods.as_deref().filter(|t| !t.is_empty()) {
+                    Some(periods) => Some(
+                        self.resolve_time_periods(&workspace_gid, periods)
+                            .await?
+                            .join(","),
+                    ),
+                    None => None,
+                };
+                let mut query = vec![("workspace", workspace_gid.as_str())];
+                if let Some(time_periods) = &time_periods {
+                    query.push(("time_periods", time_periods));
+                }
+                query.push(("opt_fields", &fields));
+                let goals = self
+                    .client
+                    .get_all_limited::<Resource>("/goals", &query, p.limit)
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to list goals", e))?;
+                limited_list_response(&goals, &fields, p.format, p.with_metadata)
+            }
+
+            ResourceType::TimePeriods => {
+                let workspace_gid = self.resolve_workspace_gid(p.gid.as_deref()).await?;
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(TIME_PERIOD_FIELDS));
+                let periods = self
+                    .client
+                    .get_all_limited::<Resource>(
+                        "/time_periods",
+                        &[("workspace", &workspace_gid), ("opt_fields", &fields)],
+                        p.limit,
+                    )
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to list time periods", e))?;
+                limited_list_response(&periods, &fields, p.format, p.with_metadata)
+            }
+
+            ResourceType::GoalRelationships => {
+                let gid = require_gid(&p.gid, "goal_relationships (goal GID)")?;
+                let fields = resolve_fields_from_get_params(
+                    &p,
+                    self.default_fields(GOAL_RELATIONSHIP_FIELDS),
+                );
+                let relationships: Vec<Resource> = self
+                    .client
+                    .get_all(
+                        "/goal_relationships",
+                        &[("supported_goal", &gid), ("opt_fields", &fields)],
+                    )
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to get goal relationships", e))?;
+                json_response(&relationships)
+            }
+
+            ResourceType::TaskAttachments => {
+                let gid = require_gid(&p.gid, "task_attachments (parent GID)")?;
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(ATTACHMENT_FIELDS));
+                let attachments: Vec<Resource> = self
+                    .client
+                    .get_all("/attachments", &[("parent", &gid), ("opt_fields", &fields)])
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to list attachments", e))?;
+                json_response(&attachments)
+            }
+
+            ResourceType::Attachment => {
+                let gid = require_gid(&p.gid, "attachment")?;
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(ATTACHMENT_FIELDS));
+                let attachment: Resource = self
+                    .client
+                    .get(&format!("/attachments/{}", gid), &[("opt_fields", &fields)])
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to get attachment", e))?;
+                json_response(&attachment)
+            }
+
+            ResourceType::Webhooks => {
+                let workspace_gid = self.resolve_workspace_gid(p.gid.as_deref()).await?;
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(WEBHOOK_FIELDS));
+                let webhooks = self
+                    .client
+                    .get_all_limited::<Resource>(
+                        "/webhooks",
+                        &[("workspace", &workspace_gid), ("opt_fields", &fields)],
+                        p.limit,
+                    )
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to list webhooks", e))?;
+                limited_list_response(&webhooks, &fields, p.format, p.with_metadata)
+            }
+
+            ResourceType::AuditLog => {
+                let workspace_gid = self.resolve_workspace_gid(p.gid.as_deref()).await?;
+                let filters = [
+                    ("start_at", &p.start_at),
+                    ("end_at", &p.end_at),
+                    ("event_type", &p.event_type),
+                    ("actor_gid", &p.actor_gid),
+                ];
+                let query: Vec<(&str, &str)> = filters
+                    .iter()
+                    .filter_map(|(key, value)| value.as_deref().map(|v| (*key, v)))
+                    .collect();
+                let events = self
+                    .client
+                    .get_all_limited::<Resource>(
+                        &format!("/workspaces/{}/audit_log_events", workspace_gid),
+                        &query,
+                        Some(p.limit.unwrap_or(DEFAULT_AUDIT_LOG_LIMIT)),
+                    )
+                    .await
+                    .map_err(|e| match e {
+                        Error::Forbidden(msg) => validation_error(&format!(
+                            "Failed to list audit log events: {} (the audit log API requires a \
+                             service account token from an Enterprise organization admin)",
+                            msg
+                        )),
+                        e => error_to_mcp("Failed to list audit log events", e),
+                    })?;
+                limited_json_response(&events, p.with_metadata)
+            }
+
+            ResourceType::Webhook => {
+                let gid = require_gid(&p.gid, "webhook")?;
+                let fields =
+                    resolve_fields_from_get_params(&p, self.default_fields(WEBHOOK_FIELDS));
+                let webhook: Resource = self
+                    .client
+                    .get(&format!("/webhooks/{}", gid), &[("opt_fields", &fields)])
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to get webhook", e))?;
+                json_response(&webhook)
+            }
+        }
+    }
+
+    /// Create Asana resources.
+    #[tool(description = "Create a new Asana resource. Supports:\n\
+            - task: Create a task (workspace_gid or project_gid, uses default workspace if neither). \
+            resource_subtype: default_task (default), milestone, or approval. \
+            due_at/start_at (ISO 8601) set a time instead of due_on/start_on. \
+            custom_fields keys may be field names and enum values option names when project_gid is given. \
+            section_gid (with project_gid) places it in a section; add insert_before or insert_after (a task GID \
+            in that section) to position it\n\
+            - subtask: Create a subtask (task_gid = parent task)\n\
+            - project: Create a project (workspace_gid or team_gid required)\n\
+            - project_from_template: Instantiate from template (template_gid required)\n\
+            - task_from_template: Instantiate a task template (template_gid required, name optional). Returns a job with new_task.\n\
+            - portfolio: Create a portfolio (uses default workspace if workspace_gid not provided)\n\
             - section: Create a section in a project (project_gid required)\n\
             - comment: Add a comment to a task (task_gid required)\n\
-            - status_update: Create a status update (parent_gid = project/portfolio)\n\
+            - status_update: Create a status update (parent_gid = project/portfolio, \
+            status_type = on_track/at_risk/off_track/on_hold/complete)\n\
             - tag: Create a tag (uses default workspace if workspace_gid not provided)\n\
             - project_duplicate: Duplicate a project (source_gid, name required; include[] for options)\n\
             - task_duplicate: Duplicate a task (source_gid, name required; include[] for options)\n\
-            - project_brief: Create a project brief (project_gid required, html_text with <body> tags). This is the 'Key Resources' on the Overview tab (NOT the Note tab).\n\n\
-            workspace_gid uses ASANA_DEFAULT_WORKSPACE env var if not provided.")]
+            - project_brief: Create a project brief (project_gid required, html_text with <body> tags). This is the 'Key Resources' on the Overview tab (NOT the Note tab).\n\
+            - goal: Create a goal (name required; workspace_gid, team_gid, time_period, owner, notes, due_on, start_on optional). \
+            Optional metric {unit, precision, currency_code, initial_number_value, target_number_value, current_number_value} is set after creation.\n\
+            - attachment: Attach an external file by URL to a task (task_gid, url required; name optional)\n\
+            - webhook: Register a webhook (parent_gid = resource to watch, url = HTTPS target; filters[] of \
+            {resource_type, resource_subtype, action, fields} optional). The target must echo the X-Hook-Secret \
+            request header back in its response, since Asana performs this handshake before the webhook is created.\n\
+            - custom_field: Create a custom field (name, field_type required; enum_options[] of {name, color} for enum/multi_enum; \
+            uses default workspace if workspace_gid not provided)\n\
+            - enum_option: Add an option to an enum custom field (custom_field_gid, name required; color optional; \
+            insert_before or insert_after = an existing option GID to position it, otherwise it is appended)\n\n\
+            workspace_gid uses ASANA_DEFAULT_WORKSPACE env var if not provided.\n\
+            wait_for_job: For project_from_template, task_from_template, project_duplicate, and task_duplicate, \
+            poll the job until it finishes and return the new project or task instead of the job.\n\
+            Creates are never retried (Asana has no idempotency support); after a timeout, check whether the \
+            resource exists before creating it again.\n\
+            resolve_names: Set true to pass names instead of GIDs in project_gid, task_gid, team_gid, or template_gid; \
+            each is resolved by typeahead in the workspace and ambiguous names are rejected.\n\
+            opt_fields: Fields to return for the created resource, e.g. [\"gid\", \"name\", \"permalink_url\", \"assignee.name\"]. \
+            detail_level=minimal returns only gid and name.")]
     async fn asana_create(
         &self,
         params: Parameters<CreateParams>,
     ) -> Result<CallToolResult, McpError> {
-        let p = params.0;
+        let mut p = params.0;
+        if let Some(account) = p.account.take() {
+            let server = self.for_account(&account)?;
+            return Box::pin(server.asana_create(Parameters(p))).await;
+        }
+        if p.dry_run {
+            p.dry_run = false;
+            let call = move |server: Self| async move { server.asana_create(Parameters(p)).await };
+            return Box::pin(self.dry_run(call)).await;
+        }
+        if p.resolve_names {
+            self.resolve_create_names(&mut p).await?;
+        }
+        let opt_fields = p.opt_fields.take();
+        let with_fields = |path: &str, default_fields: Option<&str>| {
+            path_with_fields(path, p.detail_level, &opt_fields, default_fields)
+        };
 
         match p.resource_type {
             CreateResourceType::Task => {
+                check_task_dates(&p.due_on, &p.due_at, &p.start_on, &p.start_at)?;
+                if p.section_gid.is_some() && p.project_gid.is_none() {
+                    return Err(validation_error("project_gid is required with section_gid"));
+                }
+                if p.insert_before.is_some() && p.insert_after.is_some() {
+                    return Err(validation_error(
+                        "Provide only one of insert_before or insert_after",
+                    ));
+                }
+                if (p.insert_before.is_some() || p.insert_after.is_some())
+                    && p.section_gid.is_none()
+                {
+                    return Err(validation_error(
+                        "section_gid is required with insert_before or insert_after",
+                    ));
+                }
+                let custom_fields = match p.custom_fields {
+                    Some(cf) => Some(
+                        self.resolve_custom_fields(cf, p.project_gid.as_deref(), None)
+                            .await?,
+                    ),
+                    None => None,
+                };
                 let mut data = serde_json::Map::new();
                 if let Some(name) = p.name {
                     data.insert("name".to_string(), serde_json::json!(name));
@@ -611,7 +1565,7 @@ impl AsanaServer {
                 if let Some(ws) = p.workspace_gid {
                     data.insert("workspace".to_string(), serde_json::json!(ws));
                 }
-                if let Some(proj) = p.project_gid {
+                if let Some(proj) = &p.project_gid {
                     data.insert("projects".to_string(), serde_json::json!([proj]));
                 }
                 if let Some(assignee) = p.assignee {
@@ -623,26 +1577,71 @@ impl AsanaServer {
                 if let Some(start_on) = p.start_on {
                     data.insert("start_on".to_string(), serde_json::json!(start_on));
                 }
+                if let Some(due_at) = p.due_at {
+                    data.insert("due_at".to_string(), serde_json::json!(due_at));
+                }
+                if let Some(start_at) = p.start_at {
+                    data.insert("start_at".to_string(), serde_json::json!(start_at));
+                }
                 if let Some(notes) = p.notes {
                     data.insert("notes".to_string(), serde_json::json!(notes));
                 }
                 if let Some(html_notes) = p.html_notes {
                     data.insert("html_notes".to_string(), serde_json::json!(html_notes));
                 }
-                if let Some(cf) = p.custom_fields {
+                if let Some(cf) = custom_fields {
                     data.insert("custom_fields".to_string(), serde_json::json!(cf));
                 }
+                if let Some(subtype) = p.resource_subtype {
+                    data.insert("resource_subtype".to_string(), serde_json::json!(subtype));
+                }
 
                 let body = serde_json::json!({"data": data});
                 let task: Resource = self
                     .client
-                    .post("/tasks", &body)
+                    .post(&with_fields("/tasks", None), &body)
                     .await
                     .map_err(|e| error_to_mcp("Failed to create task", e))?;
+
+                // Tasks are created in a project's first section; move it with a follow-up call
+                let (Some(project_gid), Some(section_gid)) = (p.project_gid, p.section_gid) else {
+                    return json_response(&task);
+                };
+                let result = if p.insert_before.is_some() || p.insert_after.is_some() {
+                    let mut data = serde_json::Map::new();
+                    data.insert("task".to_string(), serde_json::json!(task.gid));
+                    if let Some(before) = p.insert_before {
+                        data.insert("insert_before".to_string(), serde_json::json!(before));
+                    }
+                    if let Some(after) = p.insert_after {
+                        data.insert("insert_after".to_string(), serde_json::json!(after));
+                    }
+                    let body = serde_json::json!({"data": data});
+                    self.client
+                        .post_empty(&format!("/sections/{}/addTask", section_gid), &body)
+                        .await
+                } else {
+                    let body = serde_json::json!({
+                        "data": {"project": project_gid, "section": section_gid}
+                    });
+                    self.client
+                        .post_empty(&format!("/tasks/{}/addProject", task.gid), &body)
+                        .await
+                };
+                result.map_err(|e| {
+                    error_to_mcp(
+                        &format!(
+                            "Task {} was created but placing it in section {} failed",
+                            task.gid, section_gid
+                        ),
+                        e,
+                    )
+                })?;
                 json_response(&task)
             }
 
             CreateResourceType::Subtask => {
+                check_task_dates(&p.due_on, &p.due_at, &p.start_on, &p.start_at)?;
                 let task_gid = p
                     .task_gid
                     .ok_or_else(|| validation_error("task_gid is required for subtask"))?;
@@ -659,6 +1658,12 @@ impl AsanaServer {
                 if let Some(start_on) = p.start_on {
                     data.insert("start_on".to_string(), serde_json::json!(start_on));
                 }
+                if let Some(due_at) = p.due_at {
+                    data.insert("due_at".to_string(), serde_json::json!(due_at));
+                }
+                if let Some(start_at) = p.start_at {
+                    data.insert("start_at".to_string(), serde_json::json!(start_at));
+                }
                 if let Some(notes) = p.notes {
                     data.insert("notes".to_string(), serde_json::json!(notes));
                 }
@@ -666,13 +1671,20 @@ impl AsanaServer {
                     data.insert("html_notes".to_string(), serde_json::json!(html_notes));
                 }
                 if let Some(cf) = p.custom_fields {
+                    let cf = self.resolve_custom_fields(cf, None, None).await?;
                     data.insert("custom_fields".to_string(), serde_json::json!(cf));
                 }
+                if let Some(subtype) = p.resource_subtype {
+                    data.insert("resource_subtype".to_string(), serde_json::json!(subtype));
+                }
 
                 let body = serde_json::json!({"data": data});
                 let task: Resource = self
                     .client
-                    .post(&format!("/tasks/{}/subtasks", task_gid), &body)
+                    .post(
+                        &with_fields(&format!("/tasks/{}/subtasks", task_gid), None),
+                        &body,
+                    )
                     .await
                     .map_err(|e| error_to_mcp("Failed to create subtask", e))?;
                 json_response(&task)
@@ -682,6 +1694,7 @@ impl AsanaServer {
                 let name = p
                     .name
                     .ok_or_else(|| validation_error("name is required for project"))?;
+                let mut warnings = Vec::new();
                 let mut data = serde_json::Map::new();
                 data.insert("name".to_string(), serde_json::json!(name));
                 if let Some(ws) = p.workspace_gid {
@@ -691,6 +1704,7 @@ impl AsanaServer {
                     data.insert("team".to_string(), serde_json::json!(team));
                 }
                 if let Some(color) = p.color {
+                    warnings.extend(unknown_color_warning(&color));
                     data.insert("color".to_string(), serde_json::json!(color));
                 }
                 if let Some(notes) = p.notes {
@@ -712,10 +1726,10 @@ impl AsanaServer {
                 let body = serde_json::json!({"data": data});
                 let project: Resource = self
                     .client
-                    .post("/projects", &body)
+                    .post(&with_fields("/projects", None), &body)
                     .await
                     .map_err(|e| error_to_mcp("Failed to create project", e))?;
-                json_response(&project)
+                warnings_response(&project, &warnings)
             }
 
             CreateResourceType::ProjectFromTemplate => {
@@ -743,24 +1757,66 @@ impl AsanaServer {
                 let job: Job = self
                     .client
                     .post(
-                        &format!("/project_templates/{}/instantiateProject", template_gid),
+                        &format!(
+                            "/project_templates/{}/instantiateProject?opt_fields={}",
+                            template_gid, JOB_FIELDS
+                        ),
                         &body,
                     )
                     .await
                     .map_err(|e| error_to_mcp("Failed to instantiate project from template", e))?;
+                if p.wait_for_job {
+                    return self
+                        .wait_for_job(job, "Failed to instantiate project from template")
+                        .await;
+                }
+                json_response(&job)
+            }
+
+            CreateResourceType::TaskFromTemplate => {
+                let template_gid = p
+                    .template_gid
+                    .ok_or_else(|| validation_error("template_gid is required"))?;
+
+                let mut data = serde_json::Map::new();
+                if let Some(name) = p.name {
+                    data.insert("name".to_string(), serde_json::json!(name));
+                }
+
+                let body = serde_json::json!({"data": data});
+                let job: Job = self
+                    .client
+                    .post(
+                        &format!(
+                            "/task_templates/{}/instantiateTask?opt_fields={}",
+                            template_gid, JOB_FIELDS
+                        ),
+                        &body,
+                    )
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to instantiate task from template", e))?;
+                if p.wait_for_job {
+                    return self
+                        .wait_for_job(job, "Failed to instantiate task from template")
+                        .await;
+                }
                 json_response(&job)
             }
 
             CreateResourceType::Portfolio => {
-                let workspace_gid = self.resolve_workspace_gid(p.workspace_gid.as_deref())?;
+                let workspace_gid = self
+                    .resolve_workspace_gid(p.workspace_gid.as_deref())
+                    .await?;
                 let name = p
                     .name
                     .ok_or_else(|| validation_error("name is required for portfolio"))?;
 
+                let mut warnings = Vec::new();
                 let mut data = serde_json::Map::new();
                 data.insert("name".to_string(), serde_json::json!(name));
                 data.insert("workspace".to_string(), serde_json::json!(workspace_gid));
                 if let Some(color) = p.color {
+                    warnings.extend(unknown_color_warning(&color));
                     data.insert("color".to_string(), serde_json::json!(color));
                 }
                 if let Some(public) = p.public {
@@ -770,10 +1826,10 @@ impl AsanaServer {
                 let body = serde_json::json!({"data": data});
                 let portfolio: Resource = self
                     .client
-                    .post("/portfolios", &body)
+                    .post(&with_fields("/portfolios", None), &body)
                     .await
                     .map_err(|e| error_to_mcp("Failed to create portfolio", e))?;
-                json_response(&portfolio)
+                warnings_response(&portfolio, &warnings)
             }
 
             CreateResourceType::Section => {
@@ -787,7 +1843,10 @@ impl AsanaServer {
                 let body = serde_json::json!({"data": {"name": name}});
                 let section: Resource = self
                     .client
-                    .post(&format!("/projects/{}/sections", project_gid), &body)
+                    .post(
+                        &with_fields(&format!("/projects/{}/sections", project_gid), None),
+                        &body,
+                    )
                     .await
                     .map_err(|e| error_to_mcp("Failed to create section", e))?;
                 json_response(&section)
@@ -812,7 +1871,10 @@ impl AsanaServer {
                 let body = serde_json::json!({"data": data});
                 let story: Resource = self
                     .client
-                    .post(&format!("/tasks/{}/stories", task_gid), &body)
+                    .post(
+                        &with_fields(&format!("/tasks/{}/stories", task_gid), None),
+                        &body,
+                    )
                     .await
                     .map_err(|e| error_to_mcp("Failed to create comment", e))?;
                 json_response(&story)
@@ -839,22 +1901,26 @@ impl AsanaServer {
                 let body = serde_json::json!({"data": data});
                 let status: Resource = self
                     .client
-                    .post("/status_updates", &body)
+                    .post(&with_fields("/status_updates", None), &body)
                     .await
                     .map_err(|e| error_to_mcp("Failed to create status update", e))?;
                 json_response(&status)
             }
 
             CreateResourceType::Tag => {
-                let workspace_gid = self.resolve_workspace_gid(p.workspace_gid.as_deref())?;
+                let workspace_gid = self
+                    .resolve_workspace_gid(p.workspace_gid.as_deref())
+                    .await?;
                 let name = p
                     .name
                     .ok_or_else(|| validation_error("name is required for tag"))?;
 
+                let mut warnings = Vec::new();
                 let mut data = serde_json::Map::new();
                 data.insert("name".to_string(), serde_json::json!(name));
                 data.insert("workspace".to_string(), serde_json::json!(workspace_gid));
                 if let Some(color) = p.color {
+                    warnings.extend(unknown_color_warning(&color));
                     data.insert("color".to_string(), serde_json::json!(color));
                 }
                 if let Some(notes) = p.notes {
@@ -864,10 +1930,10 @@ impl AsanaServer {
                 let body = serde_json::json!({"data": data});
                 let tag: Resource = self
                     .client
-                    .post("/tags", &body)
+                    .post(&with_fields("/tags", None), &body)
                     .await
                     .map_err(|e| error_to_mcp("Failed to create tag", e))?;
-                json_response(&tag)
+                warnings_response(&tag, &warnings)
             }
 
             CreateResourceType::ProjectDuplicate => {
@@ -888,11 +1954,20 @@ impl AsanaServer {
                 }
 
                 let body = serde_json::json!({"data": data});
-                let job: Resource = self
+                let job: Job = self
                     .client
-                    .post(&format!("/projects/{}/duplicate", source_gid), &body)
+                    .post(
+                        &format!(
+                            "/projects/{}/duplicate?opt_fields={}",
+                            source_gid, JOB_FIELDS
+                        ),
+                        &body,
+                    )
                     .await
                     .map_err(|e| error_to_mcp("Failed to duplicate project", e))?;
+                if p.wait_for_job {
+                    return self.wait_for_job(job, "Failed to duplicate project").await;
+                }
                 json_response(&job)
             }
 
@@ -911,12 +1986,18 @@ impl AsanaServer {
                 }
 
                 let body = serde_json::json!({"data": data});
-                let task: Resource = self
+                let job: Job = self
                     .client
-                    .post(&format!("/tasks/{}/duplicate", source_gid), &body)
+                    .post(
+                        &format!("/tasks/{}/duplicate?opt_fields={}", source_gid, JOB_FIELDS),
+                        &body,
+                    )
                     .await
                     .map_err(|e| error_to_mcp("Failed to duplicate task", e))?;
-                json_response(&task)
+                if p.wait_for_job {
+                    return self.wait_for_job(job, "Failed to duplicate task").await;
+                }
+                json_response(&job)
             }
 
             CreateResourceType::ProjectBrief => {
@@ -941,11 +2022,216 @@ impl AsanaServer {
                 let body = serde_json::json!({"data": data});
                 let brief: Resource = self
                     .client
-                    .post(&format!("/projects/{}/project_briefs", project_gid), &body)
+                    .post(
+                        &with_fields(&format!("/projects/{}/project_briefs", project_gid), None),
+                        &body,
+                    )
                     .await
                     .map_err(|e| error_to_mcp("Failed to create project brief", e))?;
                 json_response(&brief)
             }
+
+            CreateResourceType::Goal => {
+                let name = p
+                    .name
+                    .ok_or_else(|| validation_error("name is required for goal"))?;
+                let workspace_gid = self
+                    .resolve_workspace_gid(p.workspace_gid.as_deref())
+                    .await?;
+
+                let mut data = serde_json::Map::new();
+                data.insert("name".to_string(), serde_json::json!(name));
+                data.insert("workspace".to_string(), serde_json::json!(workspace_gid));
+                if let Some(team) = p.team_gid {
+                    data.insert("team".to_string(), serde_json::json!(team));
+                }
+                if let Some(time_period) = p.time_period {
+                    data.insert("time_period".to_string(), serde_json::json!(time_period));
+                }
+                if let Some(owner) = p.owner {
+                    data.insert("owner".to_string(), serde_json::json!(owner));
+                }
+                if let Some(notes) = p.notes {
+                    data.insert("notes".to_string(), serde_json::json!(notes));
+                }
+                if let Some(html_notes) = p.html_notes {
+                    data.insert("html_notes".to_string(), serde_json::json!(html_notes));
+                }
+                if let Some(due_on) = p.due_on {
+                    data.insert("due_on".to_string(), serde_json::json!(due_on));
+                }
+                if let Some(start_on) = p.start_on {
+                    data.insert("start_on".to_string(), serde_json::json!(start_on));
+                }
+
+                let body = serde_json::json!({"data": data});
+                let goal: Resource = self
+                    .client
+                    .post(&with_fields("/goals", None), &body)
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to create goal", e))?;
+
+                // Metrics can't be set in the create call; Asana requires a separate setMetric
+                let Some(metric) = p.metric else {
+                    return json_response(&goal);
+                };
+                let body = serde_json::json!({"data": metric});
+                let goal: Resource = self
+                    .client
+                    .post(
+                        &with_fields(&format!("/goals/{}/setMetric", goal.gid), None),
+                        &body,
+                    )
+                    .await
+                    .map_err(|e| {
+                        error_to_mcp(
+                            &format!(
+                                "Goal {} was created but setting its metric failed",
+                                goal.gid
+                            ),
+                            e,
+                        )
+                    })?;
+                json_response(&goal)
+            }
+
+            CreateResourceType::Attachment => {
+                let task_gid = p
+                    .task_gid
+                    .ok_or_else(|| validation_error("task_gid is required for attachment"))?;
+                let url = p
+                    .url
+                    .ok_or_else(|| validation_error("url is required for attachment"))?;
+                let name = p.name.unwrap_or_else(|| url.clone());
+
+                // createAttachmentForObject takes opt_fields in the query, not the form
+                let attachment: Resource = self
+                    .client
+                    .post_multipart(
+                        &with_fields("/attachments", Some(self.default_fields(ATTACHMENT_FIELDS))),
+                        &[
+                            ("parent", task_gid.as_str()),
+                            ("resource_subtype", "external"),
+                            ("url", url.as_str()),
+                            ("name", name.as_str()),
+                        ],
+                    )
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to create attachment", e))?;
+                json_response(&attachment)
+            }
+
+            CreateResourceType::Webhook => {
+                let resource = p.parent_gid.ok_or_else(|| {
+                    validation_error("parent_gid (the resource to watch) is required for webhook")
+                })?;
+                let target = p
+                    .url
+                    .ok_or_else(|| validation_error("url (the target) is required for webhook"))?;
+
+                let mut data = serde_json::Map::new();
+                data.insert("resource".to_string(), serde_json::json!(resource));
+                data.insert("target".to_string(), serde_json::json!(target));
+                if let Some(filters) = p.filters {
+                    data.insert("filters".to_string(), serde_json::json!(filters));
+                }
+
+                // Asana completes the X-Hook-Secret handshake with the target before
+                // responding, so this call only succeeds if the target echoes the header.
+                let body = serde_json::json!({"data": data});
+                let webhook: Resource = self
+                    .client
+                    .post(
+                        &with_fields("/webhooks", Some(self.default_fields(WEBHOOK_FIELDS))),
+                        &body,
+                    )
+                    .await
+                    .map_err(|e| {
+                        error_to_mcp(
+                            "Failed to create webhook (the target must echo the X-Hook-Secret \
+                             header back to complete Asana's handshake)",
+                            e,
+                        )
+                    })?;
+                json_response(&webhook)
+            }
+
+            CreateResourceType::CustomField => {
+                let workspace_gid = self
+                    .resolve_workspace_gid(p.workspace_gid.as_deref())
+                    .await?;
+                let name = p
+                    .name
+                    .ok_or_else(|| validation_error("name is required for custom_field"))?;
+                let field_type = p
+                    .field_type
+                    .ok_or_else(|| validation_error("field_type is required for custom_field"))?;
+
+                let mut data = serde_json::Map::new();
+                data.insert("workspace".to_string(), serde_json::json!(workspace_gid));
+                data.insert("name".to_string(), serde_json::json!(name));
+                data.insert(
+                    "resource_subtype".to_string(),
+                    serde_json::json!(field_type),
+                );
+                if let Some(enum_options) = p.enum_options {
+                    data.insert("enum_options".to_string(), serde_json::json!(enum_options));
+                }
+
+                let body = serde_json::json!({"data": data});
+                let custom_field: Resource = self
+                    .client
+                    .post(
+                        &with_fields(
+                            "/custom_fields",
+                            Some(self.default_fields(CUSTOM_FIELD_FIELDS)),
+                        ),
+                        &body,
+                    )
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to create custom field", e))?;
+                json_response(&custom_field)
+            }
+
+            CreateResourceType::EnumOption => {
+                let custom_field_gid = p.custom_field_gid.ok_or_else(|| {
+                    validation_error("custom_field_gid is required for enum_option")
+                })?;
+                let name = p
+                    .name
+                    .ok_or_else(|| validation_error("name is required for enum_option"))?;
+                if p.insert_before.is_some() && p.insert_after.is_some() {
+                    return Err(validation_error(
+                        "Provide only one of insert_before or insert_after",
+                    ));
+                }
+
+                let mut data = serde_json::Map::new();
+                data.insert("name".to_string(), serde_json::json!(name));
+                if let Some(color) = p.color {
+                    data.insert("color".to_string(), serde_json::json!(color));
+                }
+                if let Some(before) = p.insert_before {
+                    data.insert("insert_before".to_string(), serde_json::json!(before));
+                }
+                if let Some(after) = p.insert_after {
+                    data.insert("insert_after".to_string(), serde_json::json!(after));
+                }
+
+                let body = serde_json::json!({"data": data});
+                let enum_option: Resource = self
+                    .client
+                    .post(
+                        &with_fields(
+                            &format!("/custom_fields/{}/enum_options", custom_field_gid),
+                            Some(ENUM_OPTION_FIELDS),
+                        ),
+                        &body,
+                    )
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to create enum option", e))?;
+                json_response(&enum_option)
+            }
         }
     }
 
@@ -954,23 +2240,41 @@ impl AsanaServer {
         description = "Update an existing Asana resource. Provide gid and only the fields to change.\n\
             \n\
             Resource types and their fields:\n\
-            - task: name, assignee, due_on, start_on, completed, notes, html_notes, custom_fields\n\
-            - project: name, notes, html_notes, color, archived, public, privacy_setting, owner, custom_fields\n\
+            - task: name, assignee, due_on, start_on (or due_at, start_at as ISO 8601 date-times), completed, notes, html_notes, \
+            custom_fields ({field GID or name: value}; enum options may be given by name), \
+            resource_subtype (default_task/milestone/approval), assignee_section (a my_task_sections GID), \
+            approval_status (pending/approved/rejected/changes_requested; approval tasks only)\n\
+            - project: name, notes, html_notes, color, archived, default_view (list/board/calendar/timeline), \
+            public, privacy_setting, owner, custom_fields\n\
             - portfolio: name, color, public\n\
             - section: name (required)\n\
             - tag: name, color, notes\n\
             - comment: text (required)\n\
-            - status_update: title, text, html_notes, status_type (on_track/at_risk/off_track)\n\
-            - project_brief: text, html_text (the 'Key Resources' on Overview tab, NOT the Note tab)"
+            - status_update: title, text, html_notes, status_type (on_track/at_risk/off_track/on_hold/complete)\n\
+            - project_brief: text, html_text (the 'Key Resources' on Overview tab, NOT the Note tab)\n\
+            \n\
+            opt_fields: Fields to return for the updated resource. detail_level=minimal returns only gid and name."
     )]
     async fn asana_update(
         &self,
         params: Parameters<UpdateParams>,
     ) -> Result<CallToolResult, McpError> {
-        let p = params.0;
+        let mut p = params.0;
+        if let Some(account) = p.account.take() {
+            let server = self.for_account(&account)?;
+            return Box::pin(server.asana_update(Parameters(p))).await;
+        }
+        if p.dry_run {
+            p.dry_run = false;
+            let call = move |server: Self| async move { server.asana_update(Parameters(p)).await };
+            return Box::pin(self.dry_run(call)).await;
+        }
+        let opt_fields = p.opt_fields.take();
+        let with_fields = |path: &str| path_with_fields(path, p.detail_level, &opt_fields, None);
 
         match p.resource_type {
             UpdateResourceType::Task => {
+                check_task_dates(&p.due_on, &p.due_at, &p.start_on, &p.start_at)?;
                 let mut data = serde_json::Map::new();
                 if let Some(name) = p.name {
                     data.insert("name".to_string(), serde_json::json!(name));
@@ -984,6 +2288,12 @@ impl AsanaServer {
                 if let Some(start_on) = p.start_on {
                     data.insert("start_on".to_string(), serde_json::json!(start_on));
                 }
+                if let Some(due_at) = p.due_at {
+                    data.insert("due_at".to_string(), serde_json::json!(due_at));
+                }
+                if let Some(start_at) = p.start_at {
+                    data.insert("start_at".to_string(), serde_json::json!(start_at));
+                }
                 if let Some(notes) = p.notes {
                     data.insert("notes".to_string(), serde_json::json!(notes));
                 }
@@ -994,24 +2304,36 @@ impl AsanaServer {
                     data.insert("completed".to_string(), serde_json::json!(completed));
                 }
                 if let Some(cf) = p.custom_fields {
+                    let cf = self.resolve_custom_fields(cf, None, Some(&p.gid)).await?;
                     data.insert("custom_fields".to_string(), serde_json::json!(cf));
                 }
+                if let Some(subtype) = p.resource_subtype {
+                    data.insert("resource_subtype".to_string(), serde_json::json!(subtype));
+                }
+                if let Some(section) = p.assignee_section {
+                    data.insert("assignee_section".to_string(), serde_json::json!(section));
+                }
+                if let Some(status) = p.approval_status {
+                    data.insert("approval_status".to_string(), serde_json::json!(status));
+                }
 
                 let body = serde_json::json!({"data": data});
                 let task: Resource = self
                     .client
-                    .put(&format!("/tasks/{}", p.gid), &body)
+                    .put(&with_fields(&format!("/tasks/{}", p.gid)), &body)
                     .await
                     .map_err(|e| error_to_mcp("Failed to update task", e))?;
                 json_response(&task)
             }
 
             UpdateResourceType::Project => {
+                let mut warnings = Vec::new();
                 let mut data = serde_json::Map::new();
                 if let Some(name) = p.name {
                     data.insert("name".to_string(), serde_json::json!(name));
                 }
                 if let Some(color) = p.color {
+                    warnings.extend(unknown_color_warning(&color));
                     data.insert("color".to_string(), serde_json::json!(color));
                 }
                 if let Some(notes) = p.notes {
@@ -1029,6 +2351,9 @@ impl AsanaServer {
                 if let Some(archived) = p.archived {
                     data.insert("archived".to_string(), serde_json::json!(archived));
                 }
+                if let Some(view) = p.default_view {
+                    data.insert("default_view".to_string(), serde_json::json!(view));
+                }
                 if let Some(privacy) = p.privacy_setting {
                     data.insert("privacy_setting".to_string(), serde_json::json!(privacy));
                 }
@@ -1042,18 +2367,20 @@ impl AsanaServer {
                 let body = serde_json::json!({"data": data});
                 let project: Resource = self
                     .client
-                    .put(&format!("/projects/{}", p.gid), &body)
+                    .put(&with_fields(&format!("/projects/{}", p.gid)), &body)
                     .await
                     .map_err(|e| error_to_mcp("Failed to update project", e))?;
-                json_response(&project)
+                warnings_response(&project, &warnings)
             }
 
             UpdateResourceType::Portfolio => {
+                let mut warnings = Vec::new();
                 let mut data = serde_json::Map::new();
                 if let Some(name) = p.name {
                     data.insert("name".to_string(), serde_json::json!(name));
                 }
                 if let Some(color) = p.color {
+                    warnings.extend(unknown_color_warning(&color));
                     data.insert("color".to_string(), serde_json::json!(color));
                 }
                 if let Some(public) = p.public {
@@ -1063,10 +2390,10 @@ impl AsanaServer {
                 let body = serde_json::json!({"data": data});
                 let portfolio: Resource = self
                     .client
-                    .put(&format!("/portfolios/{}", p.gid), &body)
+                    .put(&with_fields(&format!("/portfolios/{}", p.gid)), &body)
                     .await
                     .map_err(|e| error_to_mcp("Failed to update portfolio", e))?;
-                json_response(&portfolio)
+                warnings_response(&portfolio, &warnings)
             }
 
             UpdateResourceType::Section => {
@@ -1077,18 +2404,20 @@ impl AsanaServer {
                 let body = serde_json::json!({"data": {"name": name}});
                 let section: Resource = self
                     .client
-                    .put(&format!("/sections/{}", p.gid), &body)
+                    .put(&with_fields(&format!("/sections/{}", p.gid)), &body)
                     .await
                     .map_err(|e| error_to_mcp("Failed to update section", e))?;
                 json_response(&section)
             }
 
             UpdateResourceType::Tag => {
+                let mut warnings = Vec::new();
                 let mut data = serde_json::Map::new();
                 if let Some(name) = p.name {
                     data.insert("name".to_string(), serde_json::json!(name));
                 }
                 if let Some(color) = p.color {
+                    warnings.extend(unknown_color_warning(&color));
                     data.insert("color".to_string(), serde_json::json!(color));
                 }
                 if let Some(notes) = p.notes {
@@ -1098,10 +2427,10 @@ impl AsanaServer {
                 let body = serde_json::json!({"data": data});
                 let tag: Resource = self
                     .client
-                    .put(&format!("/tags/{}", p.gid), &body)
+                    .put(&with_fields(&format!("/tags/{}", p.gid)), &body)
                     .await
                     .map_err(|e| error_to_mcp("Failed to update tag", e))?;
-                json_response(&tag)
+                warnings_response(&tag, &warnings)
             }
 
             UpdateResourceType::Comment => {
@@ -1119,7 +2448,7 @@ impl AsanaServer {
                 let body = serde_json::json!({"data": data});
                 let story: Resource = self
                     .client
-                    .put(&format!("/stories/{}", p.gid), &body)
+                    .put(&with_fields(&format!("/stories/{}", p.gid)), &body)
                     .await
                     .map_err(|e| error_to_mcp("Failed to update comment", e))?;
                 json_response(&story)
@@ -1149,7 +2478,7 @@ impl AsanaServer {
                 let body = serde_json::json!({"data": data});
                 let status: Resource = self
                     .client
-                    .put(&format!("/status_updates/{}", p.gid), &body)
+                    .put(&with_fields(&format!("/status_updates/{}", p.gid)), &body)
                     .await
                     .map_err(|e| error_to_mcp("Failed to update status update", e))?;
                 json_response(&status)
@@ -1176,7 +2505,7 @@ impl AsanaServer {
                 let body = serde_json::json!({"data": data});
                 let brief: Resource = self
                     .client
-                    .put(&format!("/project_briefs/{}", p.gid), &body)
+                    .put(&with_fields(&format!("/project_briefs/{}", p.gid)), &body)
                     .await
                     .map_err(|e| error_to_mcp("Failed to update project brief", e))?;
                 json_response(&brief)
@@ -1187,40 +2516,107 @@ impl AsanaServer {
     /// Delete Asana resources permanently.
     #[tool(
         description = "Permanently delete an Asana resource. This action is irreversible.\n\
+            Requires confirm=true; calls without it are rejected.\n\
             \n\
             Resource types:\n\
             - task: Delete a task\n\
             - project: Delete a project\n\
             - portfolio: Delete a portfolio\n\
-            - section: Delete a section\n\
+            - section: Delete a section. Asana only deletes empty sections; pass move_tasks_to \
+            (a section GID) to move its tasks there first.\n\
             - tag: Delete a tag\n\
             - comment: Delete a comment/story\n\
             - status_update: Delete a status update\n\
-            - project_brief: Delete a project brief"
+            - project_brief: Delete a project brief\n\
+            - attachment: Delete an attachment\n\
+            - webhook: Delete a webhook"
     )]
     async fn asana_delete(
         &self,
         params: Parameters<DeleteParams>,
     ) -> Result<CallToolResult, McpError> {
-        let p = params.0;
+        let mut p = params.0;
+        if let Some(account) = p.account.take() {
+            let server = self.for_account(&account)?;
+            return Box::pin(server.asana_delete(Parameters(p))).await;
+        }
+        if p.dry_run {
+            p.dry_run = false;
+            let call = move |server: Self| async move { server.asana_delete(Parameters(p)).await };
+            return Box::pin(self.dry_run(call)).await;
+        }
         let endpoint = p.resource_type.endpoint();
         let name = p.resource_type.display_name();
 
-        self.client
-            .delete(&format!("/{}/{}", endpoint, p.gid))
-            .await
-            .map_err(|e| error_to_mcp(&format!("Failed to delete {}", name), e))?;
-        success_response(&format!("Successfully deleted {} {}", name, p.gid))
-    }
+        if p.gid.trim().is_empty() {
+            return Err(validation_error(&format!("gid is required for {}", name)));
+        }
+        if !p.confirm {
+            return Err(validation_error(&format!(
+                "confirm must be true to delete {} {}",
+                name, p.gid
+            )));
+        }
+
+        let is_section = p.resource_type == DeleteResourceType::Section;
+        let mut moved = None;
+        if let Some(target) = p.move_tasks_to.as_deref() {
+            if !is_section {
+                return Err(validation_error("move_tasks_to only applies to sections"));
+            }
+            if target == p.gid {
+                return Err(validation_error(
+                    "move_tasks_to must be a different section than the one being deleted",
+                ));
+            }
+            moved = Some(
+                self.move_section_tasks(&p.gid, target)
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to move tasks out of section", e))?,
+            );
+        }
+
+        self.client
+            .delete(&format!("/{}/{}", endpoint, p.gid))
+            .await
+            .map_err(|e| {
+                let e = match e {
+                    Error::Validation {
+                        message,
+                        help: None,
+                    } if is_section && moved.is_none() => Error::Validation {
+                        message,
+                        help: Some(
+                            "a section must be empty to be deleted; pass move_tasks_to to move \
+                             its tasks to another section first"
+                                .to_string(),
+                        ),
+                    },
+                    e => e,
+                };
+                error_to_mcp(&format!("Failed to delete {}", name), e)
+            })?;
+
+        match (moved, p.move_tasks_to) {
+            (Some(count), Some(target)) => success_response(&format!(
+                "Moved {} task(s) to section {} and deleted section {}",
+                count, target, p.gid
+            )),
+            _ => success_response(&format!("Successfully deleted {} {}", name, p.gid)),
+        }
+    }
 
     /// Manage relationships between Asana resources.
     #[tool(description = "Add or remove relationships between Asana resources.\n\
             Use action='add' or action='remove', specify relationship type, target_gid, and item_gid(s).\n\
             Use item_gid for single item, item_gids for bulk operations.\n\
+            resolve_names: Set true to pass names instead of GIDs in target_gid and item_gid(s); \
+            each is resolved by typeahead in workspace_gid (or ASANA_DEFAULT_WORKSPACE) and ambiguous names are rejected.\n\
             \n\
             Relationships:\n\
             - task_project: Add/remove a task from a project. target_gid=task GID, item_gid=project GID. \
-            Optional section_gid to place task in a specific section.\n\
+            Optional section_gid to place task in a specific section, or insert_before/insert_after \
+            (task GIDs) to position it. Adding a task already in the project reorders it.\n\
             - task_tag: Add/remove a tag from a task. target_gid=task GID, item_gid=tag GID.\n\
             - task_parent: Set/clear a task's parent (make subtask). target_gid=child task GID, \
             item_gid=parent task GID. action=remove clears the parent (item_gid not needed).\n\
@@ -1239,9 +2635,30 @@ impl AsanaServer {
             target_gid=project GID, item_gid(s)=user or team GID(s). \
             Optional access_level: admin, editor, commenter, or viewer.\n\
             - project_follower: Add/remove user(s) as followers of a project (receive notifications). \
-            target_gid=project GID, item_gid(s)=user GID(s). User GIDs only.")]
+            target_gid=project GID, item_gid(s)=user GID(s). User GIDs only.\n\
+            - task_section: Move a task to a section of a project it already belongs to. \
+            target_gid=task GID, item_gid=section GID. Supports insert_before/insert_after (task GIDs). \
+            Add only; use task_project to remove a task from a project.\n\
+            - task_like: Like (add) or unlike (remove) a task as the authenticated user. target_gid=task GID. \
+            Returns the task's liked flag and num_likes.\n\
+            - story_like: Like (add) or unlike (remove) a comment/story. target_gid=story GID. \
+            Returns the story's liked flag and num_likes.\n\
+            - goal_supporting_work: Add/remove supporting work for a goal. target_gid=goal GID, \
+            item_gid=project, portfolio, task, or subgoal GID. Optional contribution_weight (0-1) on add.")]
     async fn asana_link(&self, params: Parameters<LinkParams>) -> Result<CallToolResult, McpError> {
-        let p = params.0;
+        let mut p = params.0;
+        if let Some(account) = p.account.take() {
+            let server = self.for_account(&account)?;
+            return Box::pin(server.asana_link(Parameters(p))).await;
+        }
+        if p.dry_run {
+            p.dry_run = false;
+            let call = move |server: Self| async move { server.asana_link(Parameters(p)).await };
+            return Box::pin(self.dry_run(call)).await;
+        }
+        if p.resolve_names {
+            self.resolve_link_names(&mut p).await?;
+        }
 
         match (p.action, p.relationship) {
             // Task-Project
@@ -1249,17 +2666,39 @@ impl AsanaServer {
                 let project_gid = p
                     .item_gid
                     .ok_or_else(|| validation_error("item_gid (project) is required"))?;
+                let positions = [&p.section_gid, &p.insert_before, &p.insert_after];
+                if positions.iter().filter(|gid| gid.is_some()).count() > 1 {
+                    return Err(validation_error(
+                        "Provide only one of section_gid, insert_before, or insert_after",
+                    ));
+                }
+
+                // Adding a task that is already in the project just repositions it
+                let reordering = p.insert_before.is_some() || p.insert_after.is_some();
                 let mut data = serde_json::Map::new();
                 data.insert("project".to_string(), serde_json::json!(project_gid));
                 if let Some(section) = p.section_gid {
                     data.insert("section".to_string(), serde_json::json!(section));
                 }
+                if let Some(before) = p.insert_before {
+                    data.insert("insert_before".to_string(), serde_json::json!(before));
+                }
+                if let Some(after) = p.insert_after {
+                    data.insert("insert_after".to_string(), serde_json::json!(after));
+                }
                 let body = serde_json::json!({"data": data});
                 self.client
                     .post_empty(&format!("/tasks/{}/addProject", p.target_gid), &body)
                     .await
                     .map_err(|e| error_to_mcp("Failed to add task to project", e))?;
-                success_response("Task added to project")
+                if reordering {
+                    success_response(&format!(
+                        "Task {} moved in project {}",
+                        p.target_gid, project_gid
+                    ))
+                } else {
+                    success_response("Task added to project")
+                }
             }
             (LinkAction::Remove, RelationshipType::TaskProject) => {
                 let project_gid = p
@@ -1367,22 +2806,28 @@ impl AsanaServer {
             (LinkAction::Add, RelationshipType::TaskFollower) => {
                 let gids = get_item_gids(&p)?;
                 let body = serde_json::json!({"data": {"followers": gids}});
-                self.client
-                    .post_empty(&format!("/tasks/{}/addFollowers", p.target_gid), &body)
+                let task: Option<Resource> = self
+                    .client
+                    .post_optional(&format!("/tasks/{}/addFollowers", p.target_gid), &body)
                     .await
                     .map_err(|e| error_to_mcp("Failed to add followers", e))?;
-                success_response("Followers added")
+                match task {
+                    Some(task) => json_response(&task),
+                    None => success_response("Followers added"),
+                }
             }
             (LinkAction::Remove, RelationshipType::TaskFollower) => {
-                let gid = p
-                    .item_gid
-                    .ok_or_else(|| validation_error("item_gid (follower) is required"))?;
-                let body = serde_json::json!({"data": {"followers": [gid]}});
-                self.client
-                    .post_empty(&format!("/tasks/{}/removeFollowers", p.target_gid), &body)
+                let gids = get_item_gids(&p)?;
+                let body = serde_json::json!({"data": {"followers": gids}});
+                let task: Option<Resource> = self
+                    .client
+                    .post_optional(&format!("/tasks/{}/removeFollowers", p.target_gid), &body)
                     .await
-                    .map_err(|e| error_to_mcp("Failed to remove follower", e))?;
-                success_response("Follower removed")
+                    .map_err(|e| error_to_mcp("Failed to remove followers", e))?;
+                match task {
+                    Some(task) => json_response(&task),
+                    None => success_response("Followers removed"),
+                }
             }
 
             // Portfolio-Item
@@ -1471,24 +2916,108 @@ impl AsanaServer {
             // Project-Follower
             (LinkAction::Add, RelationshipType::ProjectFollower) => {
                 let gids = get_item_gids(&p)?;
-                let body = serde_json::json!({"data": {"followers": gids.join(",")}});
-                self.client
-                    .post_empty(&format!("/projects/{}/addFollowers", p.target_gid), &body)
+                let body = serde_json::json!({"data": {"followers": gids}});
+                let project: Option<Resource> = self
+                    .client
+                    .post_optional(&format!("/projects/{}/addFollowers", p.target_gid), &body)
                     .await
                     .map_err(|e| error_to_mcp("Failed to add project followers", e))?;
-                success_response("Followers added to project")
+                match project {
+                    Some(project) => json_response(&project),
+                    None => success_response("Followers added to project"),
+                }
             }
             (LinkAction::Remove, RelationshipType::ProjectFollower) => {
                 let gids = get_item_gids(&p)?;
-                let body = serde_json::json!({"data": {"followers": gids.join(",")}});
-                self.client
-                    .post_empty(
+                let body = serde_json::json!({"data": {"followers": gids}});
+                let project: Option<Resource> = self
+                    .client
+                    .post_optional(
                         &format!("/projects/{}/removeFollowers", p.target_gid),
                         &body,
                     )
                     .await
                     .map_err(|e| error_to_mcp("Failed to remove project followers", e))?;
-                success_response("Followers removed from project")
+                match project {
+                    Some(project) => json_response(&project),
+                    None => success_response("Followers removed from project"),
+                }
+            }
+
+            // Task-Section
+            (LinkAction::Add, RelationshipType::TaskSection) => {
+                let section_gid = p
+                    .item_gid
+                    .ok_or_else(|| validation_error("item_gid (section) is required"))?;
+                let mut data = serde_json::Map::new();
+                data.insert("task".to_string(), serde_json::json!(p.target_gid));
+                if let Some(before) = p.insert_before {
+                    data.insert("insert_before".to_string(), serde_json::json!(before));
+                }
+                if let Some(after) = p.insert_after {
+                    data.insert("insert_after".to_string(), serde_json::json!(after));
+                }
+                let body = serde_json::json!({"data": data});
+                self.client
+                    .post_empty(&format!("/sections/{}/addTask", section_gid), &body)
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to move task to section", e))?;
+                success_response(&format!(
+                    "Task {} moved to section {}",
+                    p.target_gid, section_gid
+                ))
+            }
+            (LinkAction::Remove, RelationshipType::TaskSection) => Err(validation_error(
+                "A task can't be removed from a section, only moved. Use action=add with the \
+                 destination section, or relationship=task_project to remove it from the project",
+            )),
+
+            // Goal supporting work
+            (LinkAction::Add, RelationshipType::GoalSupportingWork) => {
+                let item_gid = p.item_gid.ok_or_else(|| {
+                    validation_error("item_gid (supporting resource) is required")
+                })?;
+                let mut data = serde_json::Map::new();
+                data.insert(
+                    "supporting_resource".to_string(),
+                    serde_json::json!(item_gid),
+                );
+                if let Some(weight) = p.contribution_weight {
+                    data.insert("contribution_weight".to_string(), serde_json::json!(weight));
+                }
+                let body = serde_json::json!({"data": data});
+                self.client
+                    .post_empty(
+                        &format!("/goals/{}/addSupportingRelationship", p.target_gid),
+                        &body,
+                    )
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to add supporting work to goal", e))?;
+                success_response("Supporting work added to goal")
+            }
+            (LinkAction::Remove, RelationshipType::GoalSupportingWork) => {
+                let item_gid = p.item_gid.ok_or_else(|| {
+                    validation_error("item_gid (supporting resource) is required")
+                })?;
+                let body = serde_json::json!({"data": {"supporting_resource": item_gid}});
+                self.client
+                    .post_empty(
+                        &format!("/goals/{}/removeSupportingRelationship", p.target_gid),
+                        &body,
+                    )
+                    .await
+                    .map_err(|e| error_to_mcp("Failed to remove supporting work from goal", e))?;
+                success_response("Supporting work removed from goal")
+            }
+
+            // Likes
+            (action, RelationshipType::TaskLike) => {
+                self.set_liked("tasks", &p.target_gid, action == LinkAction::Add)
+                    .await
+            }
+            (action, RelationshipType::StoryLike) => {
+                self.set_liked("stories", &p.target_gid, action == LinkAction::Add)
+                    .await
             }
         }
     }
@@ -1497,58 +3026,136 @@ impl AsanaServer {
     #[tool(
         description = "Search for tasks in a workspace with filters. For searching other resource types (projects, templates, users, etc.), use asana_resource_search instead.\n\
             \n\
-            workspace_gid: Uses ASANA_DEFAULT_WORKSPACE env var if not provided\n\
+            workspace_gid: Workspace GID or name; uses ASANA_DEFAULT_WORKSPACE env var if not provided\n\
             \n\
             Filters (all optional, but at least one recommended):\n\
             - text: Search in task name and notes\n\
             - assignee: User GID, 'me' for current user, or 'null' for unassigned\n\
-            - projects: Filter by project GID(s)\n\
+            - assignees: List of user GIDs ('me' allowed); matches tasks assigned to any of them\n\
+            - assignee_not: Exclude tasks assigned to these user GID(s) ('me' allowed)\n\
+            - projects: Filter by project GID(s) (any of)\n\
+            - projects_all: Only tasks in all of these project GID(s)\n\
+            - projects_not: Exclude tasks in these project GID(s)\n\
             - tags: Filter by tag GID(s)\n\
+            - tags_not: Exclude tasks with these tag GID(s)\n\
             - sections: Filter by section GID(s)\n\
             - completed: true/false\n\
+            - has_attachment, is_subtask, is_blocked, is_blocking: true/false\n\
             - due_on, due_on_before, due_on_after: Date filters (YYYY-MM-DD)\n\
+            - due_within_days, overdue: Relative due filters resolved against today (UTC unless `today` is given)\n\
             - start_on, start_on_before, start_on_after: Start date filters\n\
             - modified_at_after, modified_at_before: Datetime filters (ISO 8601)\n\
+            - completed_on (YYYY-MM-DD), completed_at_after, completed_at_before (ISO 8601): Completion filters\n\
+            - created_on (YYYY-MM-DD), created_at_after, created_at_before (ISO 8601): Creation filters\n\
+            - created_by: Filter by creator user GID(s)\n\
             - portfolios: Filter by portfolio GID(s)\n\
+            - custom_field_filters: Map of custom field GID to conditions: value (enum option GID, number, or text), is_set, greater_than/less_than (numbers), contains/starts_with/ends_with (text)\n\
             - sort_by: due_date, created_at, completed_at, likes, modified_at\n\
-            - sort_ascending: true/false\n\n\
+            - sort_ascending: true/false\n\
+            - limit: Maximum results; when more exist the response is {data, has_more: true}\n\
+            - with_metadata: Return {count, truncated, data} instead of a bare array\n\
+            - format: json (default) or csv\n\
+            - summarize: Return counts (total, completed, incomplete, overdue, by_assignee) instead of tasks\n\n\
+            detail_level: minimal (gid, name, completed), default (adds assignee, dates, projects, tags, permalink_url), \
+            or full (default plus custom_fields with display_value).\n\
             opt_fields: Override default fields returned. Curated defaults provided."
     )]
     async fn asana_task_search(
         &self,
         params: Parameters<TaskSearchParams>,
     ) -> Result<CallToolResult, McpError> {
-        let p = params.0;
-        let workspace_gid = self.resolve_workspace_gid(p.workspace_gid.as_deref())?;
-        let fields = resolve_fields_from_task_search_params(&p, SEARCH_FIELDS);
+        let mut p = params.0;
+        if let Some(account) = p.account.take() {
+            let server = self.for_account(&account)?;
+            return Box::pin(server.asana_task_search(Parameters(p))).await;
+        }
+        let workspace_gid = self
+            .resolve_workspace_gid(p.workspace_gid.as_deref())
+            .await?;
+        let fields = if p.summarize {
+            TASK_SUMMARY_FIELDS.to_string()
+        } else {
+            resolve_fields_from_task_search_params(&p, self.default_fields(SEARCH_FIELDS))
+        };
+
+        let relative_due = p.due_within_days.is_some() || p.overdue;
+        if p.due_within_days.is_some() && p.overdue {
+            return Err(validation_error(
+                "due_within_days and overdue cannot be combined",
+            ));
+        }
+        if relative_due
+            && (p.due_on.is_some() || p.due_on_before.is_some() || p.due_on_after.is_some())
+        {
+            return Err(validation_error(
+                "due_within_days and overdue cannot be combined with due_on, due_on_before, or due_on_after",
+            ));
+        }
+        let today = match p.today.as_deref() {
+            Some(today) => today.parse::<NaiveDate>().map_err(|_| {
+                validation_error(&format!("today must be a YYYY-MM-DD date, got {:?}", today))
+            })?,
+            None => Utc::now().date_naive(),
+        };
+        let (due_after, due_before) = relative_due_bounds(today, p.due_within_days, p.overdue);
 
         // Build query parameters
-        let mut query_params: Vec<(String, String)> = vec![("opt_fields".to_string(), fields)];
+        let mut query_params: Vec<(String, String)> =
+            vec![("opt_fields".to_string(), fields.clone())];
 
         if let Some(text) = p.text {
             query_params.push(("text".to_string(), text));
         }
-        if let Some(assignee) = p.assignee {
-            if assignee == "null" {
-                query_params.push(("assignee.any".to_string(), "null".to_string()));
-            } else if assignee == "me" {
-                query_params.push(("assignee.any".to_string(), "me".to_string()));
-            } else {
-                query_params.push(("assignee.any".to_string(), assignee));
-            }
+        let assignees: Vec<String> = p
+            .assignee
+            .into_iter()
+            .chain(p.assignees.into_iter().flatten())
+            .collect();
+        if assignees.len() > 1 && assignees.iter().any(|a| a == "null") {
+            return Err(validation_error(
+                "assignee \"null\" (unassigned) cannot be combined with other assignees",
+            ));
+        }
+        if !assignees.is_empty() {
+            query_params.push(("assignee.any".to_string(), assignees.join(",")));
+        }
+        if let Some(assignee_not) = p.assignee_not {
+            query_params.push(("assignee.not".to_string(), assignee_not));
         }
         if let Some(projects) = p.projects {
             query_params.push(("projects.any".to_string(), projects.join(",")));
         }
+        if let Some(projects_not) = p.projects_not {
+            query_params.push(("projects.not".to_string(), projects_not.join(",")));
+        }
+        if let Some(projects_all) = p.projects_all {
+            query_params.push(("projects.all".to_string(), projects_all.join(",")));
+        }
         if let Some(tags) = p.tags {
             query_params.push(("tags.any".to_string(), tags.join(",")));
         }
+        if let Some(tags_not) = p.tags_not {
+            query_params.push(("tags.not".to_string(), tags_not.join(",")));
+        }
         if let Some(sections) = p.sections {
             query_params.push(("sections.any".to_string(), sections.join(",")));
         }
-        if let Some(completed) = p.completed {
+        // Overdue tasks are the incomplete ones unless the caller asked otherwise
+        if let Some(completed) = p.completed.or(p.overdue.then_some(false)) {
             query_params.push(("completed".to_string(), completed.to_string()));
         }
+        if let Some(has_attachment) = p.has_attachment {
+            query_params.push(("has_attachment".to_string(), has_attachment.to_string()));
+        }
+        if let Some(is_subtask) = p.is_subtask {
+            query_params.push(("is_subtask".to_string(), is_subtask.to_string()));
+        }
+        if let Some(is_blocked) = p.is_blocked {
+            query_params.push(("is_blocked".to_string(), is_blocked.to_string()));
+        }
+        if let Some(is_blocking) = p.is_blocking {
+            query_params.push(("is_blocking".to_string(), is_blocking.to_string()));
+        }
         if let Some(due_on) = p.due_on {
             query_params.push(("due_on".to_string(), due_on));
         }
@@ -1558,6 +3165,12 @@ impl AsanaServer {
         if let Some(due_on_after) = p.due_on_after {
             query_params.push(("due_on.after".to_string(), due_on_after));
         }
+        if let Some(due_before) = due_before {
+            query_params.push(("due_on.before".to_string(), due_before.to_string()));
+        }
+        if let Some(due_after) = due_after {
+            query_params.push(("due_on.after".to_string(), due_after.to_string()));
+        }
         if let Some(start_on) = p.start_on {
             query_params.push(("start_on".to_string(), start_on));
         }
@@ -1573,9 +3186,35 @@ impl AsanaServer {
         if let Some(modified_at_before) = p.modified_at_before {
             query_params.push(("modified_at.before".to_string(), modified_at_before));
         }
+        if let Some(completed_on) = p.completed_on {
+            query_params.push(("completed_on".to_string(), completed_on));
+        }
+        if let Some(completed_at_after) = p.completed_at_after {
+            query_params.push(("completed_at.after".to_string(), completed_at_after));
+        }
+        if let Some(completed_at_before) = p.completed_at_before {
+            query_params.push(("completed_at.before".to_string(), completed_at_before));
+        }
+        if let Some(created_on) = p.created_on {
+            query_params.push(("created_on".to_string(), created_on));
+        }
+        if let Some(created_at_after) = p.created_at_after {
+            query_params.push(("created_at.after".to_string(), created_at_after));
+        }
+        if let Some(created_at_before) = p.created_at_before {
+            query_params.push(("created_at.before".to_string(), created_at_before));
+        }
+        if let Some(created_by) = p.created_by {
+            query_params.push(("created_by.any".to_string(), created_by.join(",")));
+        }
         if let Some(portfolios) = p.portfolios {
             query_params.push(("portfolios.any".to_string(), portfolios.join(",")));
         }
+        if let Some(filters) = p.custom_field_filters {
+            for (field_gid, filter) in &filters {
+                query_params.extend(filter.to_query_params(field_gid));
+            }
+        }
         if let Some(sort_by) = p.sort_by {
             query_params.push(("sort_by".to_string(), sort_by));
         }
@@ -1589,16 +3228,21 @@ impl AsanaServer {
             .map(|(k, v)| (k.as_str(), v.as_str()))
             .collect();
 
-        let tasks: Vec<Resource> = self
+        let tasks = self
             .client
-            .get_all(
+            .get_all_limited::<Resource>(
                 &format!("/workspaces/{}/tasks/search", workspace_gid),
                 &query_refs,
+                p.limit,
             )
             .await
             .map_err(|e| error_to_mcp("Failed to search tasks", e))?;
 
-        json_response(&tasks)
+        if p.summarize {
+            return json_response(&summarize_tasks(&tasks.data, Utc::now()));
+        }
+
+        limited_list_response(&tasks, &fields, p.format, p.with_metadata)
     }
 
     /// Search for any Asana resource by name using typeahead.
@@ -1608,38 +3252,288 @@ impl AsanaServer {
             Parameters:\n\
             - query: The search text (searches resource names)\n\
             - resource_type: Type to search for - project, project_template, portfolio, user, team, tag, or goal\n\
-            - workspace_gid: Uses ASANA_DEFAULT_WORKSPACE env var if not provided\n\
-            - count: Max results to return (default 20, max 100)"
+            - workspace_gid: Workspace GID or name; uses ASANA_DEFAULT_WORKSPACE env var if not provided\n\
+            - count: Max results to return (default 20, max 100)\n\
+            - extra_fields: Fields to add to gid/name/resource_type, e.g. [\"email\"] for users\n\
+            - opt_fields: Explicit field list, overriding the defaults"
     )]
     async fn asana_resource_search(
         &self,
         params: Parameters<ResourceSearchParams>,
     ) -> Result<CallToolResult, McpError> {
-        let p = params.0;
-        let workspace_gid = self.resolve_workspace_gid(p.workspace_gid.as_deref())?;
+        let mut p = params.0;
+        if let Some(account) = p.account.take() {
+            let server = self.for_account(&account)?;
+            return Box::pin(server.asana_resource_search(Parameters(p))).await;
+        }
+        let workspace_gid = self
+            .resolve_workspace_gid(p.workspace_gid.as_deref())
+            .await?;
 
         let query = p
             .query
             .ok_or_else(|| validation_error("query is required"))?;
         let resource_type = p.resource_type.as_str();
         let count = p.count.unwrap_or(20).min(100).to_string();
-
+        let fields = resolve_fields_with_level(
+            DetailLevel::Minimal,
+            &p.extra_fields,
+            &p.opt_fields,
+            MINIMAL_FIELDS,
+        );
+
+        // Typeahead isn't paginated and takes `count` rather than `limit`
         let results: Vec<Resource> = self
             .client
-            .get_all(
+            .get_list(
                 &format!("/workspaces/{}/typeahead", workspace_gid),
                 &[
                     ("query", query.as_str()),
                     ("resource_type", resource_type),
                     ("count", &count),
-                    ("opt_fields", "gid,name,resource_type"),
+                    ("opt_fields", &fields),
                 ],
             )
             .await
-            .map_err(|e| error_to_mcp("Failed to search resources", e))?;
+            .map_err(|e| error_to_mcp("Failed to search resources", e))?
+            .data;
+
+        json_response(&results)
+    }
 
+    /// Run several Asana API calls in one round trip.
+    #[tool(
+        description = "Run up to 10 Asana API calls in a single request using Asana's batch API. \
+            Use this for many small writes (e.g. adding several tasks to a project) to save round trips and rate limit.\n\
+            \n\
+            Each action has:\n\
+            - method: get, post, put, or delete\n\
+            - relative_path: API path, e.g. /tasks/123 or /tasks/123/addProject\n\
+            - data: Request body fields (optional, without the {\"data\": ...} envelope)\n\
+            - options: Request options such as {\"fields\": [\"name\"]} (optional)\n\
+            \n\
+            Returns one result per action, in order, each with status_code and body. \
+            Individual actions can fail without failing the whole batch; check each status_code."
+    )]
+    async fn asana_batch(
+        &self,
+        params: Parameters<BatchParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let mut p = params.0;
+        if let Some(account) = p.account.take() {
+            let server = self.for_account(&account)?;
+            return Box::pin(server.asana_batch(Parameters(p))).await;
+        }
+        if p.dry_run {
+            p.dry_run = false;
+            let call = move |server: Self| async move { server.asana_batch(Parameters(p)).await };
+            return Box::pin(self.dry_run(call)).await;
+        }
+
+        if p.actions.is_empty() {
+            return Err(validation_error("actions must contain at least one action"));
+        }
+        if p.actions.len() > MAX_BATCH_ACTIONS {
+            return Err(validation_error(&format!(
+                "batch supports at most {} actions, got {}; split the work into multiple batches",
+                MAX_BATCH_ACTIONS,
+                p.actions.len()
+            )));
+        }
+
+        let results = self
+            .client
+            .batch(&p.actions)
+            .await
+            .map_err(|e| error_to_mcp("Failed to run batch", e))?;
         json_response(&results)
     }
+
+    /// Poll for changes to a resource using Asana's sync tokens.
+    #[tool(
+        description = "Get changes to an Asana resource (project, task, etc.) since the last poll.\n\
+            \n\
+            Parameters:\n\
+            - resource: GID of the resource to watch\n\
+            - sync: Sync token from the previous call. Omit on the first call.\n\
+            \n\
+            Returns {data: [events], sync, has_more}. Pass the returned sync token on the next call \
+            to receive only newer events. A first call, or one with an expired token, returns no events \
+            and a fresh token to poll from. When has_more is true, call again right away."
+    )]
+    async fn asana_events(
+        &self,
+        params: Parameters<EventsParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let mut p = params.0;
+        if let Some(account) = p.account.take() {
+            let server = self.for_account(&account)?;
+            return Box::pin(server.asana_events(Parameters(p))).await;
+        }
+        if p.resource.is_empty() {
+            return Err(validation_error("resource is required"));
+        }
+
+        let events = self
+            .client
+            .get_events(&p.resource, p.sync.as_deref().filter(|s| !s.is_empty()))
+            .await
+            .map_err(|e| error_to_mcp("Failed to get events", e))?;
+        json_response(&events)
+    }
+
+    /// Export a project with its sections, tasks, custom fields, and status updates.
+    #[tool(description = "Export a whole Asana project as one document.\n\
+            \n\
+            Parameters:\n\
+            - project_gid: GID of the project to export\n\
+            - subtask_depth: Subtask levels to include under each task: -1 = unlimited, 0 = none (default)\n\
+            - format: json (default) or markdown\n\
+            \n\
+            Returns the project with its custom field settings, most recent status updates, \
+            and sections, each with its tasks. Subtasks follow their parent task. \
+            Use asana_get for individual pieces when the full project isn't needed.")]
+    async fn asana_export(
+        &self,
+        params: Parameters<ExportParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let mut p = params.0;
+        if let Some(account) = p.account.take() {
+            let server = self.for_account(&account)?;
+            return Box::pin(server.asana_export(Parameters(p))).await;
+        }
+        if p.project_gid.is_empty() {
+            return Err(validation_error("project_gid is required"));
+        }
+        let subtask_depth = checked_depth(p.subtask_depth.unwrap_or(0), "subtask_depth")?;
+
+        let export = self
+            .export_project(&p.project_gid, subtask_depth)
+            .await
+            .map_err(|e| error_to_mcp("Failed to export project", e))?;
+
+        match p.format {
+            OutputFormat::Markdown => text_response(format::project_export_markdown(&export)),
+            OutputFormat::Json | OutputFormat::Csv => json_response(&export),
+        }
+    }
+
+    /// Mark many tasks complete or incomplete at once.
+    #[tool(
+        description = "Mark many Asana tasks complete or incomplete in one call, \
+            e.g. to close out a finished sprint.\n\
+            \n\
+            Parameters:\n\
+            - task_gids: GIDs of the tasks to update\n\
+            - completed: true to complete the tasks, false to reopen them\n\
+            \n\
+            Tasks are updated concurrently. Returns {updated: [gids], errors: [{task_gid, error}]}; \
+            one failing task does not stop the others."
+    )]
+    async fn asana_complete(
+        &self,
+        params: Parameters<CompleteParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let mut p = params.0;
+        if let Some(account) = p.account.take() {
+            let server = self.for_account(&account)?;
+            return Box::pin(server.asana_complete(Parameters(p))).await;
+        }
+        if p.dry_run {
+            p.dry_run = false;
+            let call =
+                move |server: Self| async move { server.asana_complete(Parameters(p)).await };
+            return Box::pin(self.dry_run(call)).await;
+        }
+        if p.task_gids.is_empty() {
+            return Err(validation_error("task_gids is required"));
+        }
+
+        let body = serde_json::json!({"data": {"completed": p.completed}});
+        let results: Vec<_> = stream::iter(p.task_gids)
+            .map(|gid| {
+                let body = &body;
+                async move {
+                    let result = self
+                        .client
+                        .put::<Resource, _>(&format!("/tasks/{}?opt_fields=gid", gid), body)
+                        .await;
+                    (gid, result)
+                }
+            })
+            .buffered(self.concurrency)
+            .collect()
+            .await;
+
+        let mut response = CompleteResponse {
+            updated: Vec::new(),
+            errors: Vec::new(),
+        };
+        for (task_gid, result) in results {
+            match result {
+                Ok(_) => response.updated.push(task_gid),
+                Err(e) => response.errors.push(TaskError {
+                    task_gid,
+                    error: e.to_string(),
+                }),
+            }
+        }
+        json_response(&response)
+    }
+
+    /// Call any Asana API endpoint directly.
+    #[tool(
+        description = "Call any Asana API endpoint directly and return its raw JSON response. \
+            Use this only for endpoints the other tools don't cover.\n\
+            \n\
+            Parameters:\n\
+            - method: get, post, put, or delete\n\
+            - path: API path relative to the API root, e.g. /goals/123/relationships\n\
+            - query: Query parameters, e.g. {\"opt_fields\": \"name,owner.name\"} (optional)\n\
+            - body: Request body sent as-is, including the {\"data\": ...} envelope (optional)\n\
+            - confirm: Must be true for delete\n\
+            \n\
+            Returns the full response body, e.g. {\"data\": ..., \"next_page\": ...}. \
+            Lists are not paginated automatically; pass next_page.offset as the offset query parameter."
+    )]
+    async fn asana_request(
+        &self,
+        params: Parameters<RequestParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let mut p = params.0;
+        if let Some(account) = p.account.take() {
+            let server = self.for_account(&account)?;
+            return Box::pin(server.asana_request(Parameters(p))).await;
+        }
+        if p.dry_run {
+            p.dry_run = false;
+            let call = move |server: Self| async move { server.asana_request(Parameters(p)).await };
+            return Box::pin(self.dry_run(call)).await;
+        }
+
+        if !p.path.starts_with('/') {
+            return Err(validation_error("path must start with /, e.g. /tasks/123"));
+        }
+        if p.method == BatchMethod::Delete && !p.confirm {
+            return Err(validation_error(&format!(
+                "confirm must be true to send DELETE {}",
+                p.path
+            )));
+        }
+
+        let query: Vec<(&str, &str)> = p
+            .query
+            .iter()
+            .flatten()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+        let response = self
+            .client
+            .request_json(p.method.as_str(), &p.path, &query, p.body.as_ref())
+            .await
+            .map_err(|e| error_to_mcp(&format!("Failed to {} {}", p.method.as_str(), p.path), e))?;
+        json_response(&response)
+    }
 }
 
 // ============================================================================
@@ -1647,41 +3541,319 @@ impl AsanaServer {
 // ============================================================================
 
 impl AsanaServer {
+    /// Fetch projects through Asana's `/batch` endpoint, up to ten per request.
+    ///
+    /// Batches run concurrently and results keep the order of `items`. A project whose
+    /// sub-request fails, or whose whole batch fails, is refetched on its own so the
+    /// error reported for it is the regular API error.
+    async fn batch_get_projects(
+        &self,
+        items: Vec<FavoriteItem>,
+    ) -> Vec<(FavoriteItem, Result<Resource, Error>)> {
+        let project_fields = self.default_fields(PROJECT_FIELDS);
+        let fields: Vec<&str> = project_fields.split(',').collect();
+        let fields = &fields;
+
+        let chunks: Vec<Vec<FavoriteItem>> = items
+            .chunks(MAX_BATCH_ACTIONS)
+            .map(<[FavoriteItem]>::to_vec)
+            .collect();
+
+        let results: Vec<Vec<_>> = stream::iter(chunks)
+            .map(|chunk| async move {
+                let actions: Vec<serde_json::Value> = chunk
+                    .iter()
+                    .map(|item| {
+                        serde_json::json!({
+                            "method": "get",
+                            "relative_path": format!("/projects/{}", item.gid),
+                            "options": {"fields": fields},
+                        })
+                    })
+                    .collect();
+                let batch = self.client.batch(&actions).await.ok();
+
+                let mut chunk_results = Vec::with_capacity(chunk.len());
+                for (i, item) in chunk.into_iter().enumerate() {
+                    let batched = batch
+                        .as_ref()
+                        .and_then(|results| results.get(i))
+                        .filter(|r| (200..300).contains(&r.status_code))
+                        .and_then(|r| serde_json::from_value(r.body["data"].clone()).ok());
+
+                    let result = match batched {
+                        Some(project) => Ok(project),
+                        None => {
+                            self.client
+                                .get(
+                                    &format!("/projects/{}", item.gid),
+                                    &[("opt_fields", project_fields)],
+                                )
+                                .await
+                        }
+                    };
+                    chunk_results.push((item, result));
+                }
+                chunk_results
+            })
+            .buffered(self.concurrency)
+            .collect()
+            .await;
+
+        results.into_iter().flatten().collect()
+    }
+
+    /// Poll a job until it succeeds or fails, returning the project or task it created.
+    ///
+    /// Polls with exponential backoff and gives up once `job_timeout` has elapsed, pointing
+    /// the caller at the `job` resource type so they can keep checking on it.
+    async fn wait_for_job(&self, mut job: Job, context: &str) -> Result<CallToolResult, McpError> {
+        let deadline = tokio::time::Instant::now() + self.job_timeout;
+        let mut delay = JOB_POLL_INITIAL_DELAY;
+        loop {
+            match job.status.as_deref() {
+                Some("succeeded") => {
+                    return match job.new_project.as_ref().or(job.new_task.as_ref()) {
+                        Some(resource) => json_response(resource),
+                        None => json_response(&job),
+                    };
+                }
+                Some("failed") => {
+                    return Err(to_mcp_error(context, format!("job {} failed", job.gid)));
+                }
+                _ => {}
+            }
+
+            let now = tokio::time::Instant::now();
+            if now >= deadline {
+                return Err(to_mcp_error(
+                    context,
+                    format!(
+                        "job {} is still {} after {}s; check it with asana_get resource_type job",
+                        job.gid,
+                        job.status.as_deref().unwrap_or("pending"),
+                        self.job_timeout.as_secs_f64()
+                    ),
+                ));
+            }
+            tokio::time::sleep(delay.min(deadline - now)).await;
+            delay = (delay * 2).min(JOB_POLL_MAX_DELAY);
+
+            job = self
+                .client
+                .get(&format!("/jobs/{}", job.gid), &[("opt_fields", JOB_FIELDS)])
+                .await
+                .map_err(|e| error_to_mcp(context, e))?;
+        }
+    }
+
+    /// Like or unlike a task or story, returning its updated `liked` flag and `num_likes`.
+    async fn set_liked(
+        &self,
+        endpoint: &str,
+        gid: &str,
+        liked: bool,
+    ) -> Result<CallToolResult, McpError> {
+        let body = serde_json::json!({"data": {"liked": liked}});
+        let resource: Resource = self
+            .client
+            .put(
+                &format!("/{}/{}?opt_fields={}", endpoint, gid, LIKE_FIELDS),
+                &body,
+            )
+            .await
+            .map_err(|e| {
+                let action = if liked { "like" } else { "unlike" };
+                error_to_mcp(&format!("Failed to {} {}", action, gid), e)
+            })?;
+        json_response(&resource)
+    }
+
+    /// Replace names in create parameters' GID fields with the GIDs they resolve to.
+    async fn resolve_create_names(&self, p: &mut CreateParams) -> Result<(), McpError> {
+        let workspace_gid = self
+            .resolve_workspace_gid(p.workspace_gid.as_deref())
+            .await?;
+        for (value, resource_type) in [
+            (&mut p.project_gid, "project"),
+            (&mut p.task_gid, "task"),
+            (&mut p.team_gid, "team"),
+        ] {
+            if let Some(v) = value {
+                *v = resolve_name_to_gid(&self.client, &workspace_gid, resource_type, v).await?;
+            }
+        }
+        // Typeahead can't search task templates, so only project template names resolve
+        if p.resource_type != CreateResourceType::TaskFromTemplate {
+            if let Some(v) = &mut p.template_gid {
+                *v = resolve_name_to_gid(&self.client, &workspace_gid, "project_template", v)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolve time period GIDs or display names (e.g. "Q1 FY25") to GIDs.
+    ///
+    /// The workspace's time periods are only listed when some value isn't already a GID.
+    async fn resolve_time_periods(
+        &self,
+        workspace_gid: &str,
+        values: &[String],
+    ) -> Result<Vec<String>, McpError> {
+        if values.iter().all(|v| is_gid(v)) {
+            return Ok(values.to_vec());
+        }
+        let periods: Vec<Resource> = self
+            .client
+            .get_all(
+                "/time_periods",
+                &[("workspace", workspace_gid), ("opt_fields", "display_name")],
+            )
+            .await
+            .map_err(|e| error_to_mcp("Failed to list time periods", e))?;
+        fn display_name(period: &Resource) -> &str {
+            period.str_field("display_name").unwrap_or_default()
+        }
+
+        values
+            .iter()
+            .map(|value| {
+                if is_gid(value) {
+                    return Ok(value.clone());
+                }
+                periods
+                    .iter()
+                    .find(|r| display_name(r).eq_ignore_ascii_case(value.trim()))
+                    .map(|r| r.gid.clone())
+                    .ok_or_else(|| {
+                        let names: Vec<&str> = periods.iter().map(display_name).collect();
+                        validation_error(&format!(
+                            "No time period named '{}'. Available: {}",
+                            value,
+                            names.join(", ")
+                        ))
+                    })
+            })
+            .collect()
+    }
+
+    /// Replace custom field names and enum option names in `values` with their GIDs.
+    ///
+    /// Names resolve against `project_gid`'s custom field settings when given, otherwise
+    /// against the fields already on `task_gid`. Nothing is fetched when every key and
+    /// value is a GID.
+    async fn resolve_custom_fields(
+        &self,
+        values: HashMap<String, serde_json::Value>,
+        project_gid: Option<&str>,
+        task_gid: Option<&str>,
+    ) -> Result<HashMap<String, serde_json::Value>, McpError> {
+        if !custom_fields_need_lookup(&values) {
+            return Ok(values);
+        }
+        let fields: Vec<Resource> = if let Some(project_gid) = project_gid {
+            let settings: Vec<Resource> = self
+                .client
+                .get_all(
+                    &format!("/projects/{}/custom_field_settings", project_gid),
+                    &[(
+                        "opt_fields",
+                        "custom_field.name,custom_field.enum_options.name",
+                    )],
+                )
+                .await
+                .map_err(|e| error_to_mcp("Failed to look up custom fields", e))?;
+            settings
+                .into_iter()
+                .filter_map(|s| s.fields.get("custom_field").cloned())
+                .filter_map(|f| serde_json::from_value(f).ok())
+                .collect()
+        } else if let Some(task_gid) = task_gid {
+            let task: Resource = self
+                .client
+                .get(
+                    &format!("/tasks/{}", task_gid),
+                    &[(
+                        "opt_fields",
+                        "custom_fields.name,custom_fields.enum_options.name",
+                    )],
+                )
+                .await
+                .map_err(|e| error_to_mcp("Failed to look up custom fields", e))?;
+            task.fields
+                .get("custom_fields")
+                .cloned()
+                .and_then(|f| serde_json::from_value(f).ok())
+                .unwrap_or_default()
+        } else if values.keys().all(|key| is_gid(key)) {
+            // Option names can't be resolved without knowing the fields; Asana will say so
+            return Ok(values);
+        } else {
+            return Err(validation_error(
+                "custom field names need a project_gid to resolve against; use custom field GIDs otherwise",
+            ));
+        };
+        resolve_custom_field_names(values, &fields)
+    }
+
+    /// Replace names in link parameters' target and item fields with the GIDs they resolve to.
+    async fn resolve_link_names(&self, p: &mut LinkParams) -> Result<(), McpError> {
+        let workspace_gid = self
+            .resolve_workspace_gid(p.workspace_gid.as_deref())
+            .await?;
+        let (target_type, item_type) = p.relationship.name_resource_types();
+
+        p.target_gid =
+            resolve_name_to_gid(&self.client, &workspace_gid, target_type, &p.target_gid).await?;
+
+        // "me" is accepted by Asana wherever a user GID is
+        if let Some(item_type) = item_type {
+            let items = p
+                .item_gid
+                .iter_mut()
+                .chain(p.item_gids.iter_mut().flatten());
+            for item in items.filter(|item| *item != "me") {
+                *item = resolve_name_to_gid(&self.client, &workspace_gid, item_type, item).await?;
+            }
+        }
+        Ok(())
+    }
+
     /// Get a portfolio with its items recursively expanded.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
     pub(crate) async fn get_portfolio_recursive(
         &self,
         gid: &str,
         max_depth: Option<usize>,
     ) -> Result<PortfolioWithItems, Error> {
-        self.fetch_portfolio_with_depth(gid, max_depth, 0).await
+        self.fetch_portfolio_with_depth(gid, &self.traversal(max_depth), &[], 0)
+            .await
     }
 
     fn fetch_portfolio_with_depth<'a>(
         &'a self,
         gid: &'a str,
-        max_depth: Option<usize>,
+        traversal: &'a Traversal,
+        ancestors: &'a [String],
         current_depth: usize,
     ) -> std::pin::Pin<
         Box<dyn std::future::Future<Output = Result<PortfolioWithItems, Error>> + Send + 'a>,
     > {
-        Box::pin(async move {
-            let portfolio: Resource = self
+        let fetch = async move {
+            let mut portfolio: Resource = self
                 .client
                 .get(
                     &format!("/portfolios/{}", gid),
-                    &[("opt_fields", PORTFOLIO_FIELDS)],
+                    &[("opt_fields", self.default_fields(PORTFOLIO_FIELDS))],
                 )
                 .await?;
 
-            let should_fetch_items = match max_depth {
-                None => true,
-                Some(max) => current_depth < max,
-            };
-
-            if !should_fetch_items {
+            if !traversal.expand(current_depth, &mut portfolio) {
                 return Ok(PortfolioWithItems {
                     portfolio,
                     items: Vec::new(),
+                    errors: Vec::new(),
                 });
             }
 
@@ -1693,33 +3865,77 @@ impl AsanaServer {
                 )
                 .await?;
 
-            let mut items = Vec::new();
+            let path: Vec<String> = ancestors.iter().cloned().chain([gid.to_string()]).collect();
+            let path = &path;
+
+            // Expand items concurrently; `buffered` keeps them in portfolio order. An item
+            // that fails is reported in `errors` rather than failing the whole tree.
+            let results: Vec<Result<Option<PortfolioItemExpanded>, PortfolioItemError>> =
+                stream::iter(item_refs)
+                    .map(|item_ref| async move {
+                        // A portfolio that contains one of its ancestors would recurse forever.
+                        if item_ref.resource_type == "portfolio" && path.contains(&item_ref.gid) {
+                            return Ok(unexpanded_portfolio_item(item_ref, PORTFOLIO_CYCLE));
+                        }
+                        if !traversal.spend() {
+                            return Ok(unexpanded_portfolio_item(
+                                item_ref,
+                                REQUEST_BUDGET_EXHAUSTED,
+                            ));
+                        }
+                        let expanded = match item_ref.resource_type.as_str() {
+                            "project" => self
+                                .client
+                                .get::<Resource>(
+                                    &format!("/projects/{}", item_ref.gid),
+                                    &[("opt_fields", self.default_fields(PROJECT_FIELDS))],
+                                )
+                                .await
+                                .map(|project| PortfolioItemExpanded::Project(Box::new(project))),
+                            "portfolio" => self
+                                .fetch_portfolio_with_depth(
+                                    &item_ref.gid,
+                                    traversal,
+                                    path,
+                                    current_depth + 1,
+                                )
+                                .await
+                                .map(|nested| PortfolioItemExpanded::Portfolio(Box::new(nested))),
+                            _ => return Ok(None),
+                        };
+                        expanded.map(Some).map_err(|e| PortfolioItemError {
+                            item: item_ref,
+                            error: e.to_string(),
+                        })
+                    })
+                    .buffered(self.concurrency)
+                    .collect()
+                    .await;
 
-            for item_ref in item_refs {
-                let expanded = match item_ref.resource_type.as_str() {
-                    "project" => {
-                        let project: Resource = self
-                            .client
-                            .get(
-                                &format!("/projects/{}", item_ref.gid),
-                                &[("opt_fields", PROJECT_FIELDS)],
-                            )
-                            .await?;
-                        PortfolioItemExpanded::Project(Box::new(project))
-                    }
-                    "portfolio" => {
-                        let nested = self
-                            .fetch_portfolio_with_depth(&item_ref.gid, max_depth, current_depth + 1)
-                            .await?;
-                        PortfolioItemExpanded::Portfolio(Box::new(nested))
-                    }
-                    _ => continue,
-                };
-                items.push(expanded);
+            let mut items = Vec::new();
+            let mut errors = Vec::new();
+            for result in results {
+                match result {
+                    Ok(Some(item)) => items.push(item),
+                    Ok(None) => {}
+                    Err(e) => errors.push(e),
+                }
             }
 
-            Ok(PortfolioWithItems { portfolio, items })
-        })
+            Ok(PortfolioWithItems {
+                portfolio,
+                items,
+                errors,
+            })
+        };
+
+        #[cfg(feature = "tracing")]
+        let fetch = tracing::Instrument::instrument(
+            fetch,
+            tracing::debug_span!("portfolio_level", gid, depth = current_depth),
+        );
+
+        Box::pin(fetch)
     }
 
     /// Get a task with full context.
@@ -1729,127 +3945,264 @@ impl AsanaServer {
         include_subtasks: bool,
         include_dependencies: bool,
         include_comments: bool,
+        comment_limit: Option<usize>,
     ) -> Result<TaskWithContext, Error> {
-        let task: Resource = self
-            .client
-            .get(
-                &format!("/tasks/{}", gid),
-                &[("opt_fields", TASK_FULL_FIELDS)],
-            )
-            .await?;
+        // The task and its related collections are independent, so fetch them concurrently.
+        // Sections excluded by the include_* flags resolve immediately without a request.
+        let task = async {
+            self.client
+                .get::<Resource>(
+                    &format!("/tasks/{}", gid),
+                    &[("opt_fields", self.default_fields(TASK_FULL_FIELDS))],
+                )
+                .await
+        };
 
-        let subtasks = if include_subtasks {
+        let subtasks = async {
+            if !include_subtasks {
+                return Ok(Vec::new());
+            }
             self.client
                 .get_all(
                     &format!("/tasks/{}/subtasks", gid),
-                    &[("opt_fields", SUBTASK_FIELDS)],
+                    &[("opt_fields", self.default_fields(SUBTASK_FIELDS))],
                 )
-                .await?
-        } else {
-            Vec::new()
+                .await
         };
 
-        let (dependencies, dependents) = if include_dependencies {
-            let deps: Vec<TaskDependency> = self
-                .client
-                .get_all(
+        let dependencies = async {
+            if !include_dependencies {
+                return Ok(Vec::new());
+            }
+            self.client
+                .get_all::<TaskDependency>(
                     &format!("/tasks/{}/dependencies", gid),
-                    &[("opt_fields", "gid,name,resource_type")],
+                    &[("opt_fields", DEPENDENCY_FIELDS)],
                 )
-                .await?;
-            let depts: Vec<TaskDependency> = self
-                .client
-                .get_all(
+                .await
+        };
+
+        let dependents = async {
+            if !include_dependencies {
+                return Ok(Vec::new());
+            }
+            self.client
+                .get_all::<TaskDependency>(
                     &format!("/tasks/{}/dependents", gid),
-                    &[("opt_fields", "gid,name,resource_type")],
+                    &[("opt_fields", DEPENDENCY_FIELDS)],
                 )
-                .await?;
-            (deps, depts)
-        } else {
-            (Vec::new(), Vec::new())
+                .await
         };
 
-        let comments = if include_comments {
-            let stories: Vec<Story> = self
-                .client
-                .get_all(
-                    &format!("/tasks/{}/stories", gid),
-                    &[("opt_fields", STORY_FIELDS)],
-                )
-                .await?;
-            stories.into_iter().filter(|s| s.is_comment()).collect()
-        } else {
-            Vec::new()
+        let comments = async {
+            if !include_comments {
+                return Ok(LimitedList {
+                    data: Vec::new(),
+                    has_more: false,
+                });
+            }
+            self.recent_stories(gid, self.default_fields(STORY_FIELDS), true, comment_limit)
+                .await
         };
 
+        let (task, subtasks, dependencies, dependents, comments) =
+            tokio::try_join!(task, subtasks, dependencies, dependents, comments)?;
+
         Ok(TaskWithContext {
             task,
             subtasks,
             dependencies,
             dependents,
-            comments,
+            comments: comments.data,
+            has_more_comments: comments.has_more,
+        })
+    }
+
+    /// Move every task in section `from` to the top of section `to`, keeping their order.
+    ///
+    /// Returns the number of tasks moved.
+    async fn move_section_tasks(&self, from: &str, to: &str) -> Result<usize, Error> {
+        let tasks: Vec<Resource> = self
+            .client
+            .get_all(
+                &format!("/sections/{}/tasks", from),
+                &[("opt_fields", "gid")],
+            )
+            .await?;
+
+        // addTask inserts at the top of the section, so add the last task first
+        let add_path = format!("/sections/{}/addTask", to);
+        for task in tasks.iter().rev() {
+            let body = serde_json::json!({"data": {"task": task.gid}});
+            self.client.post_empty(&add_path, &body).await?;
+        }
+        Ok(tasks.len())
+    }
+
+    /// Collect a task's stories (only comments with `comments_only`), keeping the newest `limit`.
+    ///
+    /// Asana returns stories oldest first and cannot reverse the order, so every page is
+    /// still read, but only the most recent `limit` stories are held and returned.
+    async fn recent_stories(
+        &self,
+        gid: &str,
+        fields: &str,
+        comments_only: bool,
+        limit: Option<usize>,
+    ) -> Result<LimitedList<Story>, Error> {
+        let path = format!("/tasks/{}/stories", gid);
+        let query = [("opt_fields", fields)];
+        let mut stories = pin!(self.client.stream_all::<Story>(&path, &query));
+        let mut recent = VecDeque::new();
+        let mut has_more = false;
+
+        while let Some(story) = stories.try_next().await? {
+            if comments_only && !story.is_comment() {
+                continue;
+            }
+            recent.push_back(story);
+            if limit.is_some_and(|max| recent.len() > max) {
+                recent.pop_front();
+                has_more = true;
+            }
+        }
+
+        Ok(LimitedList {
+            data: recent.into(),
+            has_more,
+        })
+    }
+
+    /// Fetch a project snapshot: details, custom field settings, recent status updates,
+    /// and every section with its tasks expanded to `subtask_depth` (`None` = unlimited).
+    pub(crate) async fn export_project(
+        &self,
+        gid: &str,
+        subtask_depth: Option<usize>,
+    ) -> Result<ProjectExport, Error> {
+        let project_path = format!("/projects/{}", gid);
+        let settings_path = format!("/projects/{}/custom_field_settings", gid);
+        let sections_path = format!("/projects/{}/sections", gid);
+        let project_query = [("opt_fields", self.default_fields(PROJECT_FIELDS))];
+        let status_query = [
+            ("parent", gid),
+            ("opt_fields", self.default_fields(STATUS_UPDATE_FIELDS)),
+        ];
+        let sections_query = [("opt_fields", self.default_fields(SECTION_FIELDS))];
+        let (project, custom_field_settings, status_updates, sections) = futures::try_join!(
+            self.client.get::<Resource>(&project_path, &project_query),
+            self.client.get_all::<Resource>(
+                &settings_path,
+                &[("opt_fields", CUSTOM_FIELD_SETTINGS_FIELDS)]
+            ),
+            self.client.get_all_limited::<Resource>(
+                "/status_updates",
+                &status_query,
+                Some(EXPORT_STATUS_UPDATES),
+            ),
+            self.client
+                .get_all::<Resource>(&sections_path, &sections_query),
+        )?;
+
+        let traversal = &self.traversal(subtask_depth);
+        let sections = stream::iter(sections)
+            .map(|section| async move {
+                let tasks: Vec<Resource> = self
+                    .client
+                    .get_all(
+                        &format!("/sections/{}/tasks", section.gid),
+                        &[("opt_fields", self.default_fields(RECURSIVE_TASK_FIELDS))],
+                    )
+                    .await?;
+                let tasks = self.expand_subtasks_flat(tasks, traversal, 0).await?;
+                Ok::<_, Error>(SectionExport { section, tasks })
+            })
+            .buffered(self.concurrency)
+            .try_collect()
+            .await?;
+
+        Ok(ProjectExport {
+            project,
+            custom_field_settings,
+            status_updates: status_updates.data,
+            sections,
         })
     }
 
     /// Get all tasks recursively from a project or portfolio.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
     pub(crate) async fn get_tasks_recursive(
         &self,
         gid: &str,
-        subtask_depth: Option<i32>,
-        portfolio_depth: Option<i32>,
+        subtask_depth: Option<usize>,
+        portfolio_depth: Option<usize>,
     ) -> Result<Vec<Resource>, Error> {
-        let portfolio_depth = portfolio_depth.unwrap_or(0);
+        let subtasks = self.traversal(subtask_depth);
 
-        // Try to detect resource type by attempting to fetch as project first
-        match self
-            .client
-            .get::<Resource>(&format!("/projects/{}", gid), &[("opt_fields", "gid")])
-            .await
-        {
-            Ok(_) => self.get_tasks_from_project(gid, subtask_depth).await,
+        // Treat the GID as a project and fall back to the portfolio path only on 404.
+        // Other errors (e.g. 403 for a project the user can't read) are returned as-is
+        // rather than being mistaken for "not a project".
+        let tasks = match self.fetch_project_tasks(gid).await {
+            Ok(tasks) => tasks,
             Err(Error::NotFound(_)) => {
-                self.get_tasks_from_portfolio(gid, subtask_depth, portfolio_depth)
+                return self
+                    .get_tasks_from_portfolio(gid, &subtasks, portfolio_depth)
                     .await
             }
-            Err(e) => Err(e),
-        }
+            Err(e) => return Err(e),
+        };
+        self.expand_subtasks_flat(tasks, &subtasks, 0).await
     }
 
     async fn get_tasks_from_project(
         &self,
         project_gid: &str,
-        subtask_depth: Option<i32>,
+        subtasks: &Traversal,
     ) -> Result<Vec<Resource>, Error> {
-        let tasks: Vec<Resource> = self
-            .client
+        let tasks = self.fetch_project_tasks(project_gid).await?;
+        self.expand_subtasks_flat(tasks, subtasks, 0).await
+    }
+
+    async fn fetch_project_tasks(&self, project_gid: &str) -> Result<Vec<Resource>, Error> {
+        self.client
             .get_all(
                 &format!("/projects/{}/tasks", project_gid),
-                &[("opt_fields", RECURSIVE_TASK_FIELDS)],
+                &[("opt_fields", self.default_fields(RECURSIVE_TASK_FIELDS))],
             )
-            .await?;
-        self.expand_subtasks_flat(tasks, subtask_depth, 0).await
+            .await
     }
 
     async fn get_tasks_from_portfolio(
         &self,
         portfolio_gid: &str,
-        subtask_depth: Option<i32>,
-        portfolio_depth: i32,
+        subtasks: &Traversal,
+        portfolio_depth: Option<usize>,
     ) -> Result<Vec<Resource>, Error> {
-        let depth = if portfolio_depth < 0 {
-            None
-        } else {
-            Some(portfolio_depth as usize)
-        };
-        let portfolio = self.get_portfolio_recursive(portfolio_gid, depth).await?;
-        let project_gids = Self::collect_project_gids_from_portfolio(&portfolio);
+        // Portfolio levels and subtask levels draw from the same request budget.
+        let portfolios = subtasks.with_depth(portfolio_depth);
+        let portfolio = self
+            .fetch_portfolio_with_depth(portfolio_gid, &portfolios, &[], 0)
+            .await?;
+        // A task list missing an unreadable project's tasks would look complete, so fail instead
+        let mut errors = Vec::new();
+        let project_gids = Self::collect_project_gids_from_portfolio(&portfolio, &mut errors);
+        if !errors.is_empty() {
+            let failed: Vec<String> = errors
+                .iter()
+                .map(|e| format!("{} {} ({})", e.item.resource_type, e.item.gid, e.error))
+                .collect();
+            return Err(Error::Api {
+                message: format!(
+                    "could not fetch every item in portfolio {}: {}",
+                    portfolio_gid,
+                    failed.join("; ")
+                ),
+            });
+        }
 
         let mut all_tasks = Vec::new();
         for project_gid in project_gids {
-            match self
-                .get_tasks_from_project(&project_gid, subtask_depth)
-                .await
-            {
+            match self.get_tasks_from_project(&project_gid, subtasks).await {
                 Ok(tasks) => all_tasks.extend(tasks),
                 Err(Error::NotFound(_)) => continue,
                 Err(e) => return Err(e),
@@ -1858,13 +4211,18 @@ impl AsanaServer {
         Ok(all_tasks)
     }
 
-    fn collect_project_gids_from_portfolio(portfolio: &PortfolioWithItems) -> Vec<String> {
+    /// Collect project GIDs from a portfolio tree, and the items that failed to load into `errors`.
+    fn collect_project_gids_from_portfolio<'a>(
+        portfolio: &'a PortfolioWithItems,
+        errors: &mut Vec<&'a PortfolioItemError>,
+    ) -> Vec<String> {
+        errors.extend(&portfolio.errors);
         let mut gids = Vec::new();
         for item in &portfolio.items {
             match item {
                 PortfolioItemExpanded::Project(p) => gids.push(p.gid.clone()),
                 PortfolioItemExpanded::Portfolio(nested) => {
-                    gids.extend(Self::collect_project_gids_from_portfolio(nested));
+                    gids.extend(Self::collect_project_gids_from_portfolio(nested, errors));
                 }
             }
         }
@@ -1874,49 +4232,56 @@ impl AsanaServer {
     fn expand_subtasks_flat<'a>(
         &'a self,
         tasks: Vec<Resource>,
-        subtask_depth: Option<i32>,
+        traversal: &'a Traversal,
         current_depth: usize,
     ) -> std::pin::Pin<
         Box<dyn std::future::Future<Output = Result<Vec<Resource>, Error>> + Send + 'a>,
     > {
         Box::pin(async move {
-            let max_depth = match subtask_depth {
-                Some(d) if d < 0 => None,
-                Some(d) => Some(d as usize),
-                None => None,
-            };
-
-            let should_fetch_subtasks = match max_depth {
-                None => true,
-                Some(max) => current_depth < max,
-            };
+            // Fetch each task's subtree concurrently; `buffered` yields results in input
+            // order, so the flattened output stays deterministic.
+            let subtrees: Vec<Vec<Resource>> = stream::iter(tasks)
+                .map(|mut task| async move {
+                    let num_subtasks = task
+                        .fields
+                        .get("num_subtasks")
+                        .and_then(|v| v.as_u64())
+                        .unwrap_or(0);
+
+                    if num_subtasks == 0 || !traversal.expand(current_depth, &mut task) {
+                        return Ok(vec![task]);
+                    }
 
-            let mut all_tasks = Vec::new();
+                    let expand = async {
+                        let subtasks: Vec<Resource> = self
+                            .client
+                            .get_all(
+                                &format!("/tasks/{}/subtasks", task.gid),
+                                &[("opt_fields", self.default_fields(RECURSIVE_TASK_FIELDS))],
+                            )
+                            .await?;
+                        self.expand_subtasks_flat(subtasks, traversal, current_depth + 1)
+                            .await
+                    };
 
-            for task in tasks {
-                let num_subtasks = task
-                    .fields
-                    .get("num_subtasks")
-                    .and_then(|v| v.as_u64())
-                    .unwrap_or(0);
+                    #[cfg(feature = "tracing")]
+                    let expand = tracing::Instrument::instrument(
+                        expand,
+                        tracing::debug_span!("task_level", gid = %task.gid, depth = current_depth),
+                    );
 
-                all_tasks.push(task.clone());
+                    let expanded = expand.await?;
 
-                if should_fetch_subtasks && num_subtasks > 0 {
-                    let subtasks: Vec<Resource> = self
-                        .client
-                        .get_all(
-                            &format!("/tasks/{}/subtasks", task.gid),
-                            &[("opt_fields", RECURSIVE_TASK_FIELDS)],
-                        )
-                        .await?;
-                    let expanded = self
-                        .expand_subtasks_flat(subtasks, subtask_depth, current_depth + 1)
-                        .await?;
-                    all_tasks.extend(expanded);
-                }
-            }
+                    let mut subtree = Vec::with_capacity(expanded.len() + 1);
+                    subtree.push(task);
+                    subtree.extend(expanded);
+                    Ok::<_, Error>(subtree)
+                })
+                .buffered(self.concurrency)
+                .try_collect()
+                .await?;
 
+            let all_tasks: Vec<Resource> = subtrees.into_iter().flatten().collect();
             Ok(all_tasks)
         })
     }
@@ -1929,6 +4294,18 @@ impl AsanaServer {
 #[tool_handler]
 impl ServerHandler for AsanaServer {
     fn get_info(&self) -> ServerInfo {
+        let mut instructions =
+            "Asana MCP server providing tools for interacting with Asana tasks, \
+             projects, and portfolios. Authenticate with ASANA_TOKEN environment variable."
+                .to_string();
+        if !self.accounts.is_empty() {
+            let names: Vec<&str> = self.accounts.keys().map(String::as_str).collect();
+            instructions.push_str(&format!(
+                " Additional accounts: {}. Pass `account` to act as one of them.",
+                names.join(", ")
+            ));
+        }
+
         ServerInfo {
             protocol_version: ProtocolVersion::V_2024_11_05,
             capabilities: ServerCapabilities::builder().enable_tools().build(),
@@ -1939,11 +4316,7 @@ impl ServerHandler for AsanaServer {
                 icons: None,
                 website_url: None,
             },
-            instructions: Some(
-                "Asana MCP server providing tools for interacting with Asana tasks, \
-                 projects, and portfolios. Authenticate with ASANA_TOKEN environment variable."
-                    .to_string(),
-            ),
+            instructions: Some(instructions),
         }
     }
 }
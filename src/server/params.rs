@@ -2,7 +2,7 @@
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Level of detail to include in responses.
 ///
@@ -19,9 +19,26 @@ pub enum DetailLevel {
     Default,
 }
 
-/// Parameters for listing workspaces (no parameters needed).
+/// Level of detail for task search results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchDetailLevel {
+    /// Just gid, name, completed
+    Minimal,
+    /// Adds assignee, dates, projects, tags, and permalink (default behavior)
+    #[default]
+    Default,
+    /// Default fields plus custom field values
+    Full,
+}
+
+/// Parameters for listing workspaces.
 #[derive(Debug, Deserialize, JsonSchema)]
-pub struct WorkspacesParams {}
+pub struct WorkspacesParams {
+    /// Named account to act as (one of ASANA_ACCOUNTS). Default: the primary ASANA_TOKEN account.
+    #[serde(default)]
+    pub account: Option<String>,
+}
 
 /// The type of resource to fetch.
 ///
@@ -30,9 +47,10 @@ pub struct WorkspacesParams {}
 ///   GID of that specific resource
 /// - `workspace_favorites`, `workspace_projects`, `workspace_templates`, `workspace_tags`:
 ///   GID of the workspace
-/// - `my_tasks`: GID of the workspace to get user's assigned tasks from
-/// - `project_tasks`: GID of the project or portfolio to get tasks from
-/// - `task_subtasks`, `task_comments`: GID of the parent task
+/// - `my_tasks`, `my_task_sections`: GID of the workspace to get user's assigned tasks from
+/// - `project_tasks`, `project_task_counts`: GID of the project (or portfolio, for
+///   `project_tasks`) to get tasks from
+/// - `task_subtasks`, `task_comments`, `task_stories`: GID of the parent task
 /// - `status_update`: GID of the status update
 /// - `status_updates`: GID of the project, portfolio, or goal
 /// - `project_sections`: GID of the project
@@ -42,6 +60,12 @@ pub struct WorkspacesParams {}
 pub enum ResourceType {
     /// Get a single project by GID
     Project,
+    /// Get a project's current status (its latest status update) by project GID
+    #[serde(rename = "project_status")]
+    ProjectStatus,
+    /// Count a project's tasks and milestones without fetching them (gid = project GID)
+    #[serde(rename = "project_task_counts", alias = "task_counts")]
+    ProjectTaskCounts,
     /// Get a portfolio with nested items (use depth parameter)
     Portfolio,
     /// Get a task with context (use include_* flags)
@@ -58,6 +82,15 @@ pub enum ResourceType {
     /// Get comments on a task (gid = task GID)
     #[serde(rename = "task_comments", alias = "comments")]
     TaskComments,
+    /// Get a task's full activity log: comments plus system stories (gid = task GID)
+    #[serde(rename = "task_stories", alias = "stories")]
+    TaskStories,
+    /// Get the tasks a task depends on (gid = task GID)
+    #[serde(rename = "task_dependencies", alias = "dependencies")]
+    TaskDependencies,
+    /// Get the tasks that depend on a task (gid = task GID)
+    #[serde(rename = "task_dependents", alias = "dependents")]
+    TaskDependents,
     /// Get a single status update by its own GID (gid = status update GID)
     #[serde(rename = "status_update")]
     StatusUpdate,
@@ -67,13 +100,23 @@ pub enum ResourceType {
     /// List all workspaces (gid is ignored)
     #[serde(rename = "all_workspaces", alias = "workspaces")]
     AllWorkspaces,
-    /// Get a single workspace by GID
+    /// Get a single workspace by GID (or the default workspace if gid is empty)
     Workspace,
     /// List project templates (gid = team GID for team templates, or empty for all accessible)
     #[serde(rename = "workspace_templates", alias = "project_templates")]
     WorkspaceTemplates,
     /// Get a single project template by GID
     ProjectTemplate,
+    /// List the date and role variables a project template needs (gid = template GID)
+    #[serde(rename = "project_template_variables")]
+    ProjectTemplateVariables,
+    /// List task templates (gid = project GID; Asana scopes task templates to projects)
+    #[serde(rename = "workspace_task_templates", alias = "task_templates")]
+    WorkspaceTaskTemplates,
+    /// Get a single task template by GID
+    TaskTemplate,
+    /// Get an async job (template instantiation or duplication) by GID
+    Job,
     /// List sections in a project (gid = project GID)
     #[serde(rename = "project_sections", alias = "sections")]
     ProjectSections,
@@ -87,12 +130,18 @@ pub enum ResourceType {
     /// Get tasks assigned to the current user in a workspace (gid = workspace GID)
     #[serde(rename = "my_tasks", alias = "my_assigned_tasks")]
     MyTasks,
+    /// List the sections of the current user's My Tasks (gid = workspace GID)
+    #[serde(rename = "my_task_sections")]
+    MyTaskSections,
     /// List all projects in a workspace (gid = workspace GID)
     #[serde(rename = "workspace_projects", alias = "projects")]
     WorkspaceProjects,
     /// Get the current authenticated user (gid is ignored)
     #[serde(alias = "current_user")]
     Me,
+    /// Get the current user with their workspaces and the default workspace (gid is ignored)
+    #[serde(rename = "whoami")]
+    WhoAmI,
     /// Get a user by GID
     User,
     /// List all users in a workspace (gid = workspace GID)
@@ -105,9 +154,19 @@ pub enum ResourceType {
     WorkspaceTeams,
     /// List users in a team (gid = team GID)
     TeamUsers,
+    /// List members of a project with their access levels (gid = project GID)
+    ProjectMemberships,
+    /// List members of a portfolio with their access levels (gid = portfolio GID)
+    PortfolioMemberships,
+    /// List members of a team with their admin and guest status (gid = team GID)
+    TeamMemberships,
     /// Get custom field settings for a project (gid = project GID)
     #[serde(rename = "project_custom_fields", alias = "custom_fields")]
     ProjectCustomFields,
+    /// Get a custom field definition by GID, including its enum options
+    CustomField,
+    /// List custom field definitions in a workspace (gid = workspace GID)
+    WorkspaceCustomFields,
     /// Get project brief by brief GID. This is the "Key Resources" content on the project Overview tab.
     /// NOTE: This is NOT the "Note" tab feature - that is a separate Asana feature without public API access.
     #[serde(rename = "project_brief")]
@@ -117,6 +176,195 @@ pub enum ResourceType {
     /// NOTE: This is NOT the "Note" tab feature - that is a separate Asana feature without public API access.
     #[serde(rename = "project_project_brief")]
     ProjectProjectBrief,
+    /// Get a single goal by GID
+    Goal,
+    /// List goals in a workspace (gid = workspace GID)
+    #[serde(rename = "workspace_goals", alias = "goals")]
+    WorkspaceGoals,
+    /// List supporting work (subgoals, projects, portfolios) for a goal (gid = goal GID)
+    #[serde(rename = "goal_relationships")]
+    GoalRelationships,
+    /// List goal time periods (quarters, halves, fiscal years) in a workspace (gid = workspace GID)
+    #[serde(rename = "time_periods")]
+    TimePeriods,
+    /// List attachments on a task, project, or project brief (gid = parent GID)
+    #[serde(rename = "task_attachments", alias = "attachments")]
+    TaskAttachments,
+    /// Get a single attachment by GID, including its download_url
+    Attachment,
+    /// List webhooks in a workspace (gid = workspace GID)
+    Webhooks,
+    /// Get a single webhook by GID
+    Webhook,
+    /// List audit log events in a workspace (gid = workspace GID; requires an admin service account)
+    #[serde(rename = "audit_log", alias = "audit_log_events")]
+    AuditLog,
+}
+
+/// Output format for tool responses.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputFormat {
+    /// Raw JSON from the Asana API (default behavior)
+    #[default]
+    Json,
+    /// Compact markdown summary (task and project only; other resources return JSON)
+    Markdown,
+    /// CSV rows for list results, with the requested fields as the header row
+    Csv,
+}
+
+/// Task subtype, controlling whether a task is a regular task, milestone, or approval.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskSubtype {
+    /// A regular task (Asana's default)
+    #[default]
+    DefaultTask,
+    /// A milestone marking a key date or deliverable
+    Milestone,
+    /// An approval request that can be approved, rejected, or sent back for changes
+    Approval,
+}
+
+/// Decision on an approval task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalStatus {
+    /// Awaiting a decision
+    Pending,
+    /// Approved by the assignee
+    Approved,
+    /// Rejected by the assignee
+    Rejected,
+    /// Sent back to the requester for changes
+    ChangesRequested,
+}
+
+/// A color from Asana's palette for projects, portfolios, and tags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum Color {
+    DarkPink,
+    DarkGreen,
+    DarkBlue,
+    DarkRed,
+    DarkTeal,
+    DarkBrown,
+    DarkOrange,
+    DarkPurple,
+    DarkWarmGray,
+    LightPink,
+    LightGreen,
+    LightBlue,
+    LightRed,
+    LightTeal,
+    LightBrown,
+    LightOrange,
+    LightPurple,
+    LightWarmGray,
+}
+
+impl Color {
+    /// Every palette color.
+    pub const ALL: [Color; 18] = [
+        Color::DarkPink,
+        Color::DarkGreen,
+        Color::DarkBlue,
+        Color::DarkRed,
+        Color::DarkTeal,
+        Color::DarkBrown,
+        Color::DarkOrange,
+        Color::DarkPurple,
+        Color::DarkWarmGray,
+        Color::LightPink,
+        Color::LightGreen,
+        Color::LightBlue,
+        Color::LightRed,
+        Color::LightTeal,
+        Color::LightBrown,
+        Color::LightOrange,
+        Color::LightPurple,
+        Color::LightWarmGray,
+    ];
+
+    /// The string Asana uses for this color, e.g. `"dark-red"`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Color::DarkPink => "dark-pink",
+            Color::DarkGreen => "dark-green",
+            Color::DarkBlue => "dark-blue",
+            Color::DarkRed => "dark-red",
+            Color::DarkTeal => "dark-teal",
+            Color::DarkBrown => "dark-brown",
+            Color::DarkOrange => "dark-orange",
+            Color::DarkPurple => "dark-purple",
+            Color::DarkWarmGray => "dark-warm-gray",
+            Color::LightPink => "light-pink",
+            Color::LightGreen => "light-green",
+            Color::LightBlue => "light-blue",
+            Color::LightRed => "light-red",
+            Color::LightTeal => "light-teal",
+            Color::LightBrown => "light-brown",
+            Color::LightOrange => "light-orange",
+            Color::LightPurple => "light-purple",
+            Color::LightWarmGray => "light-warm-gray",
+        }
+    }
+}
+
+/// A color value: one of Asana's palette colors, or any other string.
+///
+/// Unknown strings are passed through unchanged, since enum options use a different
+/// palette (e.g. "blue") and Asana may add colors, but a warning is returned for projects,
+/// portfolios, and tags, where they are usually typos.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(untagged)]
+pub enum ColorValue {
+    /// A color from Asana's project/portfolio/tag palette
+    Palette(Color),
+    /// Any other color string, sent as given
+    Other(String),
+}
+
+impl From<&str> for ColorValue {
+    fn from(value: &str) -> Self {
+        Color::ALL
+            .into_iter()
+            .find(|color| color.as_str() == value)
+            .map_or_else(|| ColorValue::Other(value.to_string()), ColorValue::Palette)
+    }
+}
+
+/// Default view of a project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjectView {
+    /// List of tasks grouped by section
+    List,
+    /// Kanban board with sections as columns
+    Board,
+    /// Calendar of tasks by due date
+    Calendar,
+    /// Timeline of tasks by start and due date. Asana's API has no separate gantt view.
+    #[serde(alias = "gantt")]
+    Timeline,
+}
+
+/// Status of a project or portfolio status update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StatusType {
+    /// On track (green)
+    OnTrack,
+    /// At risk (yellow)
+    AtRisk,
+    /// Off track (red)
+    OffTrack,
+    /// On hold (blue)
+    OnHold,
+    /// Complete
+    Complete,
 }
 
 /// Parameters for the universal get tool.
@@ -128,6 +376,42 @@ pub struct GetParams {
     /// (which fall back to ASANA_DEFAULT_WORKSPACE). Required for resource-specific operations.
     #[serde(default)]
     pub gid: Option<String>,
+    /// Maximum number of results for workspace-wide lists (workspace_projects, workspace_tags,
+    /// workspace_users, workspace_teams, workspace_goals). When more exist, the response is
+    /// {"data": [...], "has_more": true}, or CSV ending in a "# Showing the first N results"
+    /// comment line. Default: no limit.
+    #[serde(default)]
+    pub limit: Option<usize>,
+    /// Wrap workspace-wide list results as {"count", "truncated", "data"} so completeness
+    /// is always explicit; CSV ends with a "# count: N, truncated: BOOL" line instead.
+    /// Default: false.
+    #[serde(default)]
+    pub with_metadata: bool,
+    /// Output format: "json" (default), "markdown" for a compact task/project summary,
+    /// or "csv" for list results (workspace lists, project_tasks, task_subtasks); csv on a
+    /// single project or task is rejected
+    #[serde(default)]
+    pub format: OutputFormat,
+    /// For project_tasks: return counts (total, completed, overdue, by_assignee) instead
+    /// of the tasks. Default: false.
+    #[serde(default)]
+    pub summarize: bool,
+    /// For my_tasks: return [{"section", "tasks"}] groups by My Tasks section instead of a
+    /// flat task list. Default: false.
+    #[serde(default)]
+    pub group_by_section: bool,
+    /// For status_updates: return only the most recent update instead of the full
+    /// history. Default: false.
+    #[serde(default)]
+    pub latest_only: bool,
+    /// Maximum characters kept in notes, html_notes, text, and html_text fields before
+    /// they are cut with a "…(truncated)" marker. Default: 10000. Use 0 for no limit.
+    #[serde(default)]
+    pub max_text_len: Option<usize>,
+    /// Rewrite each custom_fields array as a {field name: value} map, e.g.
+    /// {"Priority": "High", "Reviewers": ["Ada"]}. Default: false (Asana's raw objects).
+    #[serde(default)]
+    pub flatten_custom_fields: bool,
     /// Portfolio/task traversal depth: -1 = unlimited, 0 = none, N = N levels
     #[serde(default)]
     pub depth: Option<i32>,
@@ -143,6 +427,26 @@ pub struct GetParams {
     /// Include comments when fetching a task (default: true)
     #[serde(default)]
     pub include_comments: Option<bool>,
+    /// For task, task_comments, and task_stories: keep only the N most recent stories.
+    /// When older ones exist this is indicated in the response. Default: 50. Use 0 for no limit.
+    #[serde(default)]
+    pub comment_limit: Option<usize>,
+    /// For audit_log: only events created at or after this ISO 8601 timestamp
+    #[serde(default)]
+    pub start_at: Option<String>,
+    /// For audit_log: only events created before this ISO 8601 timestamp
+    #[serde(default)]
+    pub end_at: Option<String>,
+    /// For audit_log: only events of this type (e.g. "user_login_succeeded")
+    #[serde(default)]
+    pub event_type: Option<String>,
+    /// For audit_log: only events performed by this user GID
+    #[serde(default)]
+    pub actor_gid: Option<String>,
+    /// For workspace_goals: only goals in these time periods, by GID or display name
+    /// (e.g. ["Q1 FY25"]). List them with resource_type=time_periods.
+    #[serde(default)]
+    pub time_periods: Option<Vec<String>>,
     /// Level of detail: "minimal" (gid/name only) or "default" (curated fields).
     /// Use minimal to reduce response size when you just need to identify resources.
     #[serde(default)]
@@ -156,6 +460,13 @@ pub struct GetParams {
     /// Example: ["gid", "name", "completed", "assignee.name"]
     #[serde(default)]
     pub opt_fields: Option<Vec<String>>,
+    /// Nested objects to return in full rather than as {gid, name} references.
+    /// Example: ["assignee", "projects"]. Passed to Asana as opt_expand.
+    #[serde(default)]
+    pub opt_expand: Option<Vec<String>>,
+    /// Named account to act as (one of ASANA_ACCOUNTS). Default: the primary ASANA_TOKEN account.
+    #[serde(default)]
+    pub account: Option<String>,
 }
 
 /// The type of resource to create.
@@ -171,6 +482,9 @@ pub enum CreateResourceType {
     /// Create a project from a template
     #[serde(rename = "project_from_template")]
     ProjectFromTemplate,
+    /// Create a task from a task template
+    #[serde(rename = "task_from_template")]
+    TaskFromTemplate,
     /// Create a new portfolio
     Portfolio,
     /// Create a section in a project
@@ -192,6 +506,18 @@ pub enum CreateResourceType {
     /// NOTE: This is NOT the "Note" tab feature - that is a separate Asana feature without public API access.
     #[serde(rename = "project_brief")]
     ProjectBrief,
+    /// Create a goal, optionally with a metric
+    Goal,
+    /// Attach an external file (by URL) to a task
+    Attachment,
+    /// Register a webhook on a resource
+    Webhook,
+    /// Create a custom field definition in a workspace
+    #[serde(rename = "custom_field")]
+    CustomField,
+    /// Add an option to an enum or multi_enum custom field
+    #[serde(rename = "enum_option")]
+    EnumOption,
 }
 
 /// Date variable for template instantiation.
@@ -203,6 +529,22 @@ pub struct DateVariableParam {
     pub value: String,
 }
 
+/// Event filter for webhook creation. Only events matching at least one filter are delivered.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct WebhookFilterParam {
+    /// Resource type to match, e.g. "task" or "story"
+    pub resource_type: String,
+    /// Resource subtype to match, e.g. "milestone" or "comment_added"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_subtype: Option<String>,
+    /// Action to match: "changed", "added", "removed", "deleted", or "undeleted"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    /// Field names to match on "changed" events, e.g. ["completed", "due_on"]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<String>>,
+}
+
 /// Role assignment for template instantiation.
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct RoleAssignmentParam {
@@ -212,6 +554,38 @@ pub struct RoleAssignmentParam {
     pub value: String,
 }
 
+/// Enum option for a new enum or multi_enum custom field.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct EnumOptionParam {
+    /// Display name of the option
+    pub name: String,
+    /// Color of the option, e.g. "blue" or "red"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+}
+
+/// Metric to attach to a goal after creating it.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct GoalMetricParam {
+    /// Unit of the metric: "none", "currency", or "percentage"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+    /// Number of decimal places to display
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub precision: Option<u32>,
+    /// ISO 4217 currency code (when unit is "currency")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency_code: Option<String>,
+    /// Starting value of the metric
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initial_number_value: Option<f64>,
+    /// Target value of the metric
+    pub target_number_value: f64,
+    /// Current value of the metric
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_number_value: Option<f64>,
+}
+
 /// Parameters for the create tool.
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct CreateParams {
@@ -223,16 +597,19 @@ pub struct CreateParams {
     /// Project GID (for task creation, section creation)
     #[serde(default)]
     pub project_gid: Option<String>,
+    /// Section GID to place a new task in (for task; requires project_gid)
+    #[serde(default)]
+    pub section_gid: Option<String>,
     /// Task GID (for subtask or comment creation)
     #[serde(default)]
     pub task_gid: Option<String>,
     /// Team GID (for project creation)
     #[serde(default)]
     pub team_gid: Option<String>,
-    /// Parent GID (for status update - project or portfolio)
+    /// Parent GID (for status update - project or portfolio; for webhook - the resource to watch)
     #[serde(default)]
     pub parent_gid: Option<String>,
-    /// Template GID (for project_from_template)
+    /// Template GID (for project_from_template, task_from_template)
     #[serde(default)]
     pub template_gid: Option<String>,
     /// Date variables for template instantiation
@@ -250,27 +627,39 @@ pub struct CreateParams {
     /// HTML notes/description
     #[serde(default)]
     pub html_notes: Option<String>,
-    /// Color (for project, portfolio, tag)
+    /// Color (for project, portfolio, tag, enum_option). Projects, portfolios, and tags use
+    /// Asana's palette, e.g. "dark-red" or "light-green"; enum options use names like "blue".
     #[serde(default)]
-    pub color: Option<String>,
+    pub color: Option<ColorValue>,
     /// Due date in YYYY-MM-DD format
     #[serde(default)]
     pub due_on: Option<String>,
     /// Start date in YYYY-MM-DD format
     #[serde(default)]
     pub start_on: Option<String>,
+    /// Due date and time in ISO 8601 format, e.g. "2024-06-01T15:00:00Z" (for task,
+    /// subtask). Use instead of due_on, not together with it.
+    #[serde(default)]
+    pub due_at: Option<String>,
+    /// Start date and time in ISO 8601 format (for task, subtask). Use instead of start_on,
+    /// not together with it.
+    #[serde(default)]
+    pub start_at: Option<String>,
     /// Assignee user GID (for task)
     #[serde(default)]
     pub assignee: Option<String>,
+    /// Task subtype (for task, subtask): "default_task", "milestone", or "approval"
+    #[serde(default)]
+    pub resource_subtype: Option<TaskSubtype>,
     /// Privacy setting (for project): "public_to_workspace" or "private_to_team"
     #[serde(default)]
     pub privacy_setting: Option<String>,
     /// Whether the resource is public
     #[serde(default)]
     pub public: Option<bool>,
-    /// Status type for status_update: "on_track", "at_risk", "off_track", etc.
+    /// Status type for status_update: "on_track", "at_risk", "off_track", "on_hold", or "complete"
     #[serde(default)]
-    pub status_type: Option<String>,
+    pub status_type: Option<StatusType>,
     /// Title (for status_update)
     #[serde(default)]
     pub title: Option<String>,
@@ -281,7 +670,8 @@ pub struct CreateParams {
     /// Cannot be used together with text; provide one or the other.
     #[serde(default)]
     pub html_text: Option<String>,
-    /// Custom field values as {field_gid: value}
+    /// Custom field values as {field_gid: value}. For tasks with project_gid, keys may be
+    /// field names and enum values option names, resolved against the project's fields.
     #[serde(default)]
     pub custom_fields: Option<HashMap<String, serde_json::Value>>,
     /// Source GID (for project_duplicate, task_duplicate - the resource to copy)
@@ -292,10 +682,64 @@ pub struct CreateParams {
     /// For task: notes, assignee, subtasks, attachments, tags, followers, projects, dates, dependencies, parent.
     #[serde(default)]
     pub include: Option<Vec<String>>,
-    /// Override default fields returned in response. If not provided, returns minimal confirmation.
-    /// Example: ["gid", "name", "permalink_url"]
+    /// Owner user GID (for goal)
+    #[serde(default)]
+    pub owner: Option<String>,
+    /// Time period GID (for goal), e.g. the quarter the goal belongs to
+    #[serde(default)]
+    pub time_period: Option<String>,
+    /// Metric to set on a newly created goal (applied via a follow-up setMetric call)
+    #[serde(default)]
+    pub metric: Option<GoalMetricParam>,
+    /// External URL to attach (for attachment), e.g. a Google Doc or S3 object.
+    /// For webhook, the HTTPS target that Asana delivers events to.
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Event filters (for webhook). Required by Asana for webhooks on workspaces, teams, and portfolios.
+    #[serde(default)]
+    pub filters: Option<Vec<WebhookFilterParam>>,
+    /// Field type (for custom_field): "text", "number", "enum", "multi_enum", "date", or "people"
+    #[serde(default)]
+    pub field_type: Option<String>,
+    /// Options for an enum or multi_enum custom_field
+    #[serde(default)]
+    pub enum_options: Option<Vec<EnumOptionParam>>,
+    /// Custom field GID (for enum_option)
+    #[serde(default)]
+    pub custom_field_gid: Option<String>,
+    /// Existing enum option GID to place the new enum_option before. For task, a task GID
+    /// in section_gid to place the new task before.
+    #[serde(default)]
+    pub insert_before: Option<String>,
+    /// Existing enum option GID to place the new enum_option after. For task, a task GID
+    /// in section_gid to place the new task after.
+    #[serde(default)]
+    pub insert_after: Option<String>,
+    /// Level of detail in the response: "minimal" (gid/name only) or "default" (Asana's
+    /// standard representation of the created resource).
+    #[serde(default)]
+    pub detail_level: DetailLevel,
+    /// Fields to return for the created resource - overrides detail_level.
+    /// Example: ["gid", "name", "permalink_url", "assignee.name"]
     #[serde(default)]
     pub opt_fields: Option<Vec<String>>,
+    /// Treat non-GID values in project_gid, task_gid, team_gid, and template_gid (project
+    /// templates only) as names and resolve them via typeahead in the workspace. Errors if a
+    /// name is ambiguous.
+    #[serde(default)]
+    pub resolve_names: bool,
+    /// For project_from_template, task_from_template, project_duplicate, and task_duplicate:
+    /// poll the resulting job until it finishes and return the new project or task.
+    #[serde(default)]
+    pub wait_for_job: bool,
+    /// Return the write requests (method, path, body) this call would send, without sending
+    /// them. Lookups such as name resolution still run, and multi-step operations stop at
+    /// their first write. Default: false.
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Named account to act as (one of ASANA_ACCOUNTS). Default: the primary ASANA_TOKEN account.
+    #[serde(default)]
+    pub account: Option<String>,
 }
 
 /// Parameters for task search (rich filtering for tasks only).
@@ -310,18 +754,45 @@ pub struct TaskSearchParams {
     /// Filter by assignee user GID (use "me" for current user, "null" for unassigned)
     #[serde(default)]
     pub assignee: Option<String>,
+    /// Match tasks assigned to any of these user GIDs ("me" allowed). Combined with `assignee`.
+    #[serde(default)]
+    pub assignees: Option<Vec<String>>,
+    /// Exclude tasks assigned to these user GID(s), comma-separated ("me" for current user)
+    #[serde(default)]
+    pub assignee_not: Option<String>,
     /// Filter by project GID(s)
     #[serde(default)]
     pub projects: Option<Vec<String>>,
+    /// Exclude tasks in any of these project GID(s)
+    #[serde(default)]
+    pub projects_not: Option<Vec<String>>,
+    /// Only tasks in all of these project GID(s)
+    #[serde(default)]
+    pub projects_all: Option<Vec<String>>,
     /// Filter by tag GID(s)
     #[serde(default)]
     pub tags: Option<Vec<String>>,
+    /// Exclude tasks with any of these tag GID(s)
+    #[serde(default)]
+    pub tags_not: Option<Vec<String>>,
     /// Filter by section GID(s)
     #[serde(default)]
     pub sections: Option<Vec<String>>,
     /// Filter by completion status
     #[serde(default)]
     pub completed: Option<bool>,
+    /// Filter by whether the task has an attachment
+    #[serde(default)]
+    pub has_attachment: Option<bool>,
+    /// Filter by whether the task is a subtask
+    #[serde(default)]
+    pub is_subtask: Option<bool>,
+    /// Filter by whether the task is waiting on an incomplete dependency
+    #[serde(default)]
+    pub is_blocked: Option<bool>,
+    /// Filter by whether an incomplete task depends on this task
+    #[serde(default)]
+    pub is_blocking: Option<bool>,
     /// Filter by tasks due on this date (YYYY-MM-DD)
     #[serde(default)]
     pub due_on: Option<String>,
@@ -331,6 +802,18 @@ pub struct TaskSearchParams {
     /// Filter by tasks due on or after this date
     #[serde(default)]
     pub due_on_after: Option<String>,
+    /// Filter by tasks due between today and this many days from now (0 = due today).
+    /// Resolved against `today`; cannot be combined with due_on, due_on_before, or due_on_after.
+    #[serde(default)]
+    pub due_within_days: Option<u32>,
+    /// Only incomplete tasks due before today. Resolved against `today`; cannot be combined
+    /// with due_within_days or explicit due date filters.
+    #[serde(default)]
+    pub overdue: bool,
+    /// Reference date (YYYY-MM-DD) for due_within_days and overdue. Default: the current
+    /// date in UTC. Pass the user's local date when they are far from UTC.
+    #[serde(default)]
+    pub today: Option<String>,
     /// Filter by tasks starting on this date
     #[serde(default)]
     pub start_on: Option<String>,
@@ -346,19 +829,60 @@ pub struct TaskSearchParams {
     /// Filter by tasks modified on or before this datetime (ISO 8601)
     #[serde(default)]
     pub modified_at_before: Option<String>,
+    /// Filter by tasks completed on this date (YYYY-MM-DD)
+    #[serde(default)]
+    pub completed_on: Option<String>,
+    /// Filter by tasks completed after this datetime (ISO 8601)
+    #[serde(default)]
+    pub completed_at_after: Option<String>,
+    /// Filter by tasks completed before this datetime (ISO 8601)
+    #[serde(default)]
+    pub completed_at_before: Option<String>,
+    /// Filter by tasks created on this date (YYYY-MM-DD)
+    #[serde(default)]
+    pub created_on: Option<String>,
+    /// Filter by tasks created after this datetime (ISO 8601)
+    #[serde(default)]
+    pub created_at_after: Option<String>,
+    /// Filter by tasks created before this datetime (ISO 8601)
+    #[serde(default)]
+    pub created_at_before: Option<String>,
+    /// Filter by creator user GID(s)
+    #[serde(default)]
+    pub created_by: Option<Vec<String>>,
     /// Filter by tasks in portfolios (GID)
     #[serde(default)]
     pub portfolios: Option<Vec<String>>,
+    /// Filter by custom field values, keyed by custom field GID.
+    /// Example: {"12345": {"value": "67890"}, "23456": {"greater_than": 3, "less_than": 8}}
+    #[serde(default)]
+    pub custom_field_filters: Option<BTreeMap<String, CustomFieldFilter>>,
+    /// Maximum number of results to return. When more exist, the response is
+    /// {"data": [...], "has_more": true}. Default: no limit.
+    #[serde(default)]
+    pub limit: Option<usize>,
+    /// Wrap results as {"count", "truncated", "data"} so completeness is always explicit.
+    /// Default: false.
+    #[serde(default)]
+    pub with_metadata: bool,
+    /// Output format: "json" (default) or "csv" with the requested fields as the header row
+    #[serde(default)]
+    pub format: OutputFormat,
+    /// Return counts (total, completed, overdue, by_assignee) instead of the tasks.
+    /// Default: false.
+    #[serde(default)]
+    pub summarize: bool,
     /// Sort by: due_date, created_at, completed_at, likes, modified_at
     #[serde(default)]
     pub sort_by: Option<String>,
     /// Sort order: asc or desc
     #[serde(default)]
     pub sort_ascending: Option<bool>,
-    /// Level of detail: "minimal" (gid/name only) or "default" (curated fields).
+    /// Level of detail: "minimal" (gid, name, completed), "default" (adds assignee, dates,
+    /// projects, tags, and permalink), or "full" (default plus custom field values).
     /// Use minimal to reduce response size when you just need to identify tasks.
     #[serde(default)]
-    pub detail_level: DetailLevel,
+    pub detail_level: SearchDetailLevel,
     /// Additional fields to include beyond the detail_level base set.
     /// Example: ["due_on", "assignee.name"] adds these to minimal or default fields.
     #[serde(default)]
@@ -368,6 +892,77 @@ pub struct TaskSearchParams {
     /// Example: ["gid", "name", "completed", "assignee.name", "due_on"]
     #[serde(default)]
     pub opt_fields: Option<Vec<String>>,
+    /// Named account to act as (one of ASANA_ACCOUNTS). Default: the primary ASANA_TOKEN account.
+    #[serde(default)]
+    pub account: Option<String>,
+}
+
+/// Conditions on a single custom field in task search.
+///
+/// Each condition that is set becomes a `custom_fields.{gid}.<suffix>` search parameter:
+/// - `value` → `.value`: enum option GID, number, or exact text
+/// - `is_set` → `.is_set`: whether the field has any value
+/// - `greater_than`, `less_than` → `.greater_than`, `.less_than`: number fields
+/// - `contains`, `starts_with`, `ends_with` → same suffixes: text fields
+#[derive(Debug, Clone, Default, Deserialize, JsonSchema)]
+pub struct CustomFieldFilter {
+    /// Exact value: enum option GID, number, or text
+    #[serde(default)]
+    pub value: Option<serde_json::Value>,
+    /// Match tasks where the field has (true) or lacks (false) a value
+    #[serde(default)]
+    pub is_set: Option<bool>,
+    /// Number fields: value strictly greater than this
+    #[serde(default)]
+    pub greater_than: Option<f64>,
+    /// Number fields: value strictly less than this
+    #[serde(default)]
+    pub less_than: Option<f64>,
+    /// Text fields: value contains this text
+    #[serde(default)]
+    pub contains: Option<String>,
+    /// Text fields: value starts with this text
+    #[serde(default)]
+    pub starts_with: Option<String>,
+    /// Text fields: value ends with this text
+    #[serde(default)]
+    pub ends_with: Option<String>,
+}
+
+impl CustomFieldFilter {
+    /// Build the search query parameters for this filter on the given custom field.
+    pub fn to_query_params(&self, field_gid: &str) -> Vec<(String, String)> {
+        let key = |suffix: &str| format!("custom_fields.{}.{}", field_gid, suffix);
+        let mut params = Vec::new();
+
+        if let Some(value) = &self.value {
+            let value = match value {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            params.push((key("value"), value));
+        }
+        if let Some(is_set) = self.is_set {
+            params.push((key("is_set"), is_set.to_string()));
+        }
+        if let Some(greater_than) = self.greater_than {
+            params.push((key("greater_than"), greater_than.to_string()));
+        }
+        if let Some(less_than) = self.less_than {
+            params.push((key("less_than"), less_than.to_string()));
+        }
+        if let Some(contains) = &self.contains {
+            params.push((key("contains"), contains.clone()));
+        }
+        if let Some(starts_with) = &self.starts_with {
+            params.push((key("starts_with"), starts_with.clone()));
+        }
+        if let Some(ends_with) = &self.ends_with {
+            params.push((key("ends_with"), ends_with.clone()));
+        }
+
+        params
+    }
 }
 
 /// Resource types that can be searched via typeahead.
@@ -419,6 +1014,16 @@ pub struct ResourceSearchParams {
     /// Maximum number of results to return (default 20, max 100)
     #[serde(default)]
     pub count: Option<u32>,
+    /// Additional fields to include beyond gid, name, and resource_type.
+    /// Example: ["email"] for users or ["color", "owner.name"] for projects.
+    #[serde(default)]
+    pub extra_fields: Option<Vec<String>>,
+    /// Explicit field list - overrides the defaults and extra_fields entirely.
+    #[serde(default)]
+    pub opt_fields: Option<Vec<String>>,
+    /// Named account to act as (one of ASANA_ACCOUNTS). Default: the primary ASANA_TOKEN account.
+    #[serde(default)]
+    pub account: Option<String>,
 }
 
 /// The type of resource to update.
@@ -471,15 +1076,35 @@ pub struct UpdateParams {
     /// New start date in YYYY-MM-DD format
     #[serde(default)]
     pub start_on: Option<String>,
+    /// New due date and time in ISO 8601 format (for task). Use instead of due_on, not
+    /// together with it.
+    #[serde(default)]
+    pub due_at: Option<String>,
+    /// New start date and time in ISO 8601 format (for task). Use instead of start_on, not
+    /// together with it.
+    #[serde(default)]
+    pub start_at: Option<String>,
     /// New assignee user GID
     #[serde(default)]
     pub assignee: Option<String>,
-    /// New color
+    /// New task subtype (for task): "default_task", "milestone", or "approval"
     #[serde(default)]
-    pub color: Option<String>,
+    pub resource_subtype: Option<TaskSubtype>,
+    /// Section of the assignee's My Tasks to move the task into (for task; see `my_task_sections`)
+    #[serde(default)]
+    pub assignee_section: Option<String>,
+    /// Decision on an approval task: "pending", "approved", "rejected", or "changes_requested"
+    #[serde(default)]
+    pub approval_status: Option<ApprovalStatus>,
+    /// New color from Asana's palette (for project, portfolio, tag), e.g. "dark-red"
+    #[serde(default)]
+    pub color: Option<ColorValue>,
     /// Archive/unarchive project
     #[serde(default)]
     pub archived: Option<bool>,
+    /// Default view (for project): "list", "board", "calendar", or "timeline"
+    #[serde(default)]
+    pub default_view: Option<ProjectView>,
     /// New privacy setting
     #[serde(default)]
     pub privacy_setting: Option<String>,
@@ -496,19 +1121,32 @@ pub struct UpdateParams {
     /// New title (for status_update)
     #[serde(default)]
     pub title: Option<String>,
-    /// New status type (for status_update): "on_track", "at_risk", "off_track", etc.
+    /// New status type (for status_update): "on_track", "at_risk", "off_track", "on_hold", or "complete"
     #[serde(default)]
-    pub status_type: Option<String>,
+    pub status_type: Option<StatusType>,
     /// New owner (user GID) for project
     #[serde(default)]
     pub owner: Option<String>,
-    /// Updated custom field values
+    /// Updated custom field values as {field_gid: value}. For tasks, keys may be field
+    /// names and enum values option names, resolved against the task's fields.
     #[serde(default)]
     pub custom_fields: Option<HashMap<String, serde_json::Value>>,
-    /// Override default fields returned in response. If not provided, returns curated fields.
+    /// Level of detail in the response: "minimal" (gid/name only) or "default" (Asana's
+    /// standard representation of the updated resource).
+    #[serde(default)]
+    pub detail_level: DetailLevel,
+    /// Fields to return for the updated resource - overrides detail_level.
     /// Example: ["gid", "name", "modified_at"]
     #[serde(default)]
     pub opt_fields: Option<Vec<String>>,
+    /// Return the write requests (method, path, body) this call would send, without sending
+    /// them. Lookups such as name resolution still run, and multi-step operations stop at
+    /// their first write. Default: false.
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Named account to act as (one of ASANA_ACCOUNTS). Default: the primary ASANA_TOKEN account.
+    #[serde(default)]
+    pub account: Option<String>,
 }
 
 /// The type of resource to delete.
@@ -534,6 +1172,10 @@ pub enum DeleteResourceType {
     /// NOTE: This is NOT the "Note" tab feature - that is a separate Asana feature without public API access.
     #[serde(rename = "project_brief")]
     ProjectBrief,
+    /// Delete an attachment
+    Attachment,
+    /// Delete a webhook
+    Webhook,
 }
 
 impl DeleteResourceType {
@@ -548,6 +1190,8 @@ impl DeleteResourceType {
             Self::Comment => "stories",
             Self::StatusUpdate => "status_updates",
             Self::ProjectBrief => "project_briefs",
+            Self::Attachment => "attachments",
+            Self::Webhook => "webhooks",
         }
     }
 
@@ -562,6 +1206,8 @@ impl DeleteResourceType {
             Self::Comment => "comment",
             Self::StatusUpdate => "status update",
             Self::ProjectBrief => "project brief",
+            Self::Attachment => "attachment",
+            Self::Webhook => "webhook",
         }
     }
 }
@@ -573,6 +1219,21 @@ pub struct DeleteParams {
     pub resource_type: DeleteResourceType,
     /// The GID of the resource to delete
     pub gid: String,
+    /// Must be true to confirm the deletion. Guards against accidental destructive calls.
+    #[serde(default)]
+    pub confirm: bool,
+    /// For section: move the section's tasks to this section GID before deleting it.
+    /// Asana only deletes empty sections.
+    #[serde(default)]
+    pub move_tasks_to: Option<String>,
+    /// Return the write requests (method, path, body) this call would send, without sending
+    /// them. Lookups such as name resolution still run, and multi-step operations stop at
+    /// their first write. Default: false.
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Named account to act as (one of ASANA_ACCOUNTS). Default: the primary ASANA_TOKEN account.
+    #[serde(default)]
+    pub account: Option<String>,
 }
 
 /// The action to perform on a relationship.
@@ -630,6 +1291,42 @@ pub enum RelationshipType {
     /// target_gid=project GID, item_gid(s)=user GID(s). User GIDs only.
     #[serde(rename = "project_follower")]
     ProjectFollower,
+    /// Move a task to a section within a project it already belongs to.
+    /// target_gid=task GID, item_gid=section GID. Supports insert_before/insert_after
+    /// (task GIDs) for ordering. Add only; use task_project to leave the project.
+    #[serde(rename = "task_section")]
+    TaskSection,
+    /// Like (add) or unlike (remove) a task as the authenticated user. target_gid=task GID.
+    #[serde(rename = "task_like")]
+    TaskLike,
+    /// Like (add) or unlike (remove) a comment/story as the authenticated user.
+    /// target_gid=story GID.
+    #[serde(rename = "story_like")]
+    StoryLike,
+    /// Add/remove supporting work for a goal. target_gid=goal GID,
+    /// item_gid=project, portfolio, task, or subgoal GID. Optional contribution_weight on add.
+    #[serde(rename = "goal_supporting_work")]
+    GoalSupportingWork,
+}
+
+impl RelationshipType {
+    /// Typeahead resource types of the target and the items, used for name resolution.
+    ///
+    /// Items of `None` can't be looked up by name.
+    pub fn name_resource_types(&self) -> (&'static str, Option<&'static str>) {
+        match self {
+            Self::TaskProject => ("task", Some("project")),
+            Self::TaskTag => ("task", Some("tag")),
+            Self::TaskParent | Self::TaskDependency | Self::TaskDependent => ("task", Some("task")),
+            Self::TaskFollower => ("task", Some("user")),
+            Self::TaskSection | Self::TaskLike => ("task", None),
+            Self::StoryLike => ("story", None),
+            Self::GoalSupportingWork => ("goal", None),
+            Self::PortfolioItem => ("portfolio", Some("project")),
+            Self::PortfolioMember => ("portfolio", Some("user")),
+            Self::ProjectMember | Self::ProjectFollower => ("project", Some("user")),
+        }
+    }
 }
 
 /// Parameters for the link tool.
@@ -660,4 +1357,150 @@ pub struct LinkParams {
     /// Supported for portfolio_member and project_member relationships.
     #[serde(default)]
     pub access_level: Option<String>,
+    /// How much the supporting work counts toward the goal's progress, from 0 to 1.
+    /// Supported for goal_supporting_work on add.
+    #[serde(default)]
+    pub contribution_weight: Option<f64>,
+    /// Treat non-GID values in target_gid and item_gid(s) as names and resolve them via
+    /// typeahead. Errors if a name is ambiguous. Section GIDs are never resolved.
+    #[serde(default)]
+    pub resolve_names: bool,
+    /// Workspace GID for name resolution (uses ASANA_DEFAULT_WORKSPACE if not provided)
+    #[serde(default)]
+    pub workspace_gid: Option<String>,
+    /// Return the write requests (method, path, body) this call would send, without sending
+    /// them. Lookups such as name resolution still run, and multi-step operations stop at
+    /// their first write. Default: false.
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Named account to act as (one of ASANA_ACCOUNTS). Default: the primary ASANA_TOKEN account.
+    #[serde(default)]
+    pub account: Option<String>,
+}
+
+/// HTTP method for a batch action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum BatchMethod {
+    /// Read a resource
+    Get,
+    /// Create a resource or call an action endpoint (e.g. /tasks/{gid}/addProject)
+    Post,
+    /// Update a resource
+    Put,
+    /// Delete a resource
+    Delete,
+}
+
+impl BatchMethod {
+    /// The HTTP method name, e.g. "GET".
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Get => "GET",
+            Self::Post => "POST",
+            Self::Put => "PUT",
+            Self::Delete => "DELETE",
+        }
+    }
+}
+
+/// A single action within a batch request.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BatchActionParam {
+    /// HTTP method for this action
+    pub method: BatchMethod,
+    /// API path relative to the API root, e.g. "/tasks/123" or "/sections/456/addTask"
+    pub relative_path: String,
+    /// Request body fields (the contents of "data", not the envelope)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+    /// Request options such as {"fields": ["name", "completed"]}
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options: Option<serde_json::Value>,
+}
+
+/// Parameters for the events tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct EventsParams {
+    /// GID of the resource to watch (e.g. a project or task)
+    pub resource: String,
+    /// Sync token from the previous call. Omit on the first call to get a starting token.
+    #[serde(default)]
+    pub sync: Option<String>,
+    /// Named account to act as (one of ASANA_ACCOUNTS). Default: the primary ASANA_TOKEN account.
+    #[serde(default)]
+    pub account: Option<String>,
+}
+
+/// Parameters for the export tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ExportParams {
+    /// GID of the project to export
+    pub project_gid: String,
+    /// Subtask expansion depth: -1 = unlimited, 0 = none (default), N = N levels
+    #[serde(default)]
+    pub subtask_depth: Option<i32>,
+    /// Output format: json (default) or markdown. csv is not supported and returns JSON.
+    #[serde(default)]
+    pub format: OutputFormat,
+    /// Named account to act as (one of ASANA_ACCOUNTS). Default: the primary ASANA_TOKEN account.
+    #[serde(default)]
+    pub account: Option<String>,
+}
+
+/// Parameters for the bulk complete tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CompleteParams {
+    /// GIDs of the tasks to update
+    pub task_gids: Vec<String>,
+    /// true to mark the tasks complete, false to reopen them
+    pub completed: bool,
+    /// Return the write requests (method, path, body) this call would send, without sending
+    /// them. Lookups such as name resolution still run, and multi-step operations stop at
+    /// their first write. Default: false.
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Named account to act as (one of ASANA_ACCOUNTS). Default: the primary ASANA_TOKEN account.
+    #[serde(default)]
+    pub account: Option<String>,
+}
+
+/// Parameters for the raw request tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RequestParams {
+    /// HTTP method: get, post, put, or delete
+    pub method: BatchMethod,
+    /// API path relative to the API root, e.g. "/goals/123/relationships"
+    pub path: String,
+    /// Query parameters, e.g. {"opt_fields": "name,owner.name", "limit": "50"}
+    #[serde(default)]
+    pub query: Option<BTreeMap<String, String>>,
+    /// Request body, sent as-is. Include Asana's envelope, e.g. {"data": {"name": "New"}}.
+    #[serde(default)]
+    pub body: Option<serde_json::Value>,
+    /// Must be true for delete requests. Guards against accidental destructive calls.
+    #[serde(default)]
+    pub confirm: bool,
+    /// Return the write request (method, path, body) this call would send, without sending
+    /// it. Default: false.
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Named account to act as (one of ASANA_ACCOUNTS). Default: the primary ASANA_TOKEN account.
+    #[serde(default)]
+    pub account: Option<String>,
+}
+
+/// Parameters for the batch tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct BatchParams {
+    /// Actions to run in one request (at most 10)
+    pub actions: Vec<BatchActionParam>,
+    /// Return the write requests (method, path, body) this call would send, without sending
+    /// them. Lookups such as name resolution still run, and multi-step operations stop at
+    /// their first write. Default: false.
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Named account to act as (one of ASANA_ACCOUNTS). Default: the primary ASANA_TOKEN account.
+    #[serde(default)]
+    pub account: Option<String>,
 }
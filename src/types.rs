@@ -3,8 +3,11 @@
 //! These types use a hybrid approach: minimal typed fields for recursion and dispatch,
 //! with remaining fields captured as raw JSON for AI consumption.
 
+use chrono::{DateTime, FixedOffset, NaiveDate};
 use serde::{Deserialize, Serialize};
 use serde_json::Map;
+use std::collections::BTreeMap;
+use std::ops::Deref;
 
 /// A globally unique identifier for an Asana resource.
 pub type Gid = String;
@@ -32,6 +35,15 @@ pub struct NextPage {
     pub offset: String,
 }
 
+/// Items collected from a paginated list, possibly stopped early at a caller's limit.
+#[derive(Debug, Clone, Serialize)]
+pub struct LimitedList<T> {
+    /// The collected items.
+    pub data: Vec<T>,
+    /// Whether more results were available beyond the limit.
+    pub has_more: bool,
+}
+
 /// A minimal wrapper for any Asana resource.
 ///
 /// Provides typed access to `gid` and `resource_type` for recursion and dispatch,
@@ -50,8 +62,158 @@ pub struct Resource {
     pub fields: Map<String, serde_json::Value>,
 }
 
+impl Resource {
+    /// Returns a top-level string field, or `None` if it is missing, null, or not a string.
+    pub fn str_field(&self, name: &str) -> Option<&str> {
+        self.fields.get(name).and_then(|v| v.as_str())
+    }
+
+    /// Returns a top-level boolean field, or `None` if it is missing, null, or not a boolean.
+    pub fn bool_field(&self, name: &str) -> Option<bool> {
+        self.fields.get(name).and_then(|v| v.as_bool())
+    }
+
+    /// Deserializes a top-level field into `T`, or `None` if it is missing, null, or doesn't fit.
+    pub fn typed_field<T: serde::de::DeserializeOwned>(&self, name: &str) -> Option<T> {
+        self.fields
+            .get(name)
+            .filter(|v| !v.is_null())
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+    }
+}
+
+/// A task with typed getters over the raw [`Resource`] fields.
+///
+/// Getters return `None` for fields that weren't requested via `opt_fields`. The
+/// underlying resource stays available through `Deref` and [`Task::into_inner`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Task(pub Resource);
+
+impl Task {
+    /// The task name.
+    pub fn name(&self) -> Option<&str> {
+        self.0.str_field("name")
+    }
+
+    /// Whether the task is completed.
+    pub fn completed(&self) -> Option<bool> {
+        self.0.bool_field("completed")
+    }
+
+    /// The assigned user, if any.
+    pub fn assignee(&self) -> Option<UserRef> {
+        self.0.typed_field("assignee")
+    }
+
+    /// The due date, for tasks due on a day.
+    pub fn due_on(&self) -> Option<NaiveDate> {
+        self.0.str_field("due_on").and_then(|s| s.parse().ok())
+    }
+
+    /// The due time, for tasks due at a specific time.
+    pub fn due_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.0
+            .str_field("due_at")
+            .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+    }
+
+    /// The plain-text description.
+    pub fn notes(&self) -> Option<&str> {
+        self.0.str_field("notes")
+    }
+
+    /// The task's URL in the Asana web app.
+    pub fn permalink_url(&self) -> Option<&str> {
+        self.0.str_field("permalink_url")
+    }
+
+    /// Returns the underlying resource.
+    pub fn into_inner(self) -> Resource {
+        self.0
+    }
+}
+
+impl Deref for Task {
+    type Target = Resource;
+
+    fn deref(&self) -> &Resource {
+        &self.0
+    }
+}
+
+impl From<Resource> for Task {
+    fn from(resource: Resource) -> Self {
+        Self(resource)
+    }
+}
+
+/// A project with typed getters over the raw [`Resource`] fields.
+///
+/// Getters return `None` for fields that weren't requested via `opt_fields`. The
+/// underlying resource stays available through `Deref` and [`Project::into_inner`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Project(pub Resource);
+
+impl Project {
+    /// The project name.
+    pub fn name(&self) -> Option<&str> {
+        self.0.str_field("name")
+    }
+
+    /// Whether the project is archived.
+    pub fn archived(&self) -> Option<bool> {
+        self.0.bool_field("archived")
+    }
+
+    /// The project owner, if any.
+    pub fn owner(&self) -> Option<UserRef> {
+        self.0.typed_field("owner")
+    }
+
+    /// The project's due date.
+    pub fn due_on(&self) -> Option<NaiveDate> {
+        self.0.str_field("due_on").and_then(|s| s.parse().ok())
+    }
+
+    /// The project's start date.
+    pub fn start_on(&self) -> Option<NaiveDate> {
+        self.0.str_field("start_on").and_then(|s| s.parse().ok())
+    }
+
+    /// The plain-text description.
+    pub fn notes(&self) -> Option<&str> {
+        self.0.str_field("notes")
+    }
+
+    /// The project's URL in the Asana web app.
+    pub fn permalink_url(&self) -> Option<&str> {
+        self.0.str_field("permalink_url")
+    }
+
+    /// Returns the underlying resource.
+    pub fn into_inner(self) -> Resource {
+        self.0
+    }
+}
+
+impl Deref for Project {
+    type Target = Resource;
+
+    fn deref(&self) -> &Resource {
+        &self.0
+    }
+}
+
+impl From<Resource> for Project {
+    fn from(resource: Resource) -> Self {
+        Self(resource)
+    }
+}
+
 /// A portfolio item reference for type dispatch during recursion.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PortfolioItem {
     /// The unique identifier.
     pub gid: Gid,
@@ -151,6 +313,51 @@ pub struct TaskDependency {
     /// The resource type.
     #[serde(default)]
     pub resource_type: Option<String>,
+
+    /// Whether the task is completed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed: Option<bool>,
+}
+
+/// The variables a project template asks for when it is instantiated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplateVariables {
+    /// The template's unique identifier.
+    pub gid: Gid,
+
+    /// The template name.
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Date variables to pass as `requested_dates`.
+    #[serde(default)]
+    pub requested_dates: Vec<TemplateVariable>,
+
+    /// Role variables to pass as `requested_roles`.
+    #[serde(default)]
+    pub requested_roles: Vec<TemplateVariable>,
+}
+
+/// A date or role variable on a project template.
+///
+/// Shaped like the `{gid, value}` pairs `project_from_template` accepts, with
+/// `value` left empty for the caller to fill in.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplateVariable {
+    /// The variable's unique identifier.
+    pub gid: Gid,
+
+    /// The variable name, e.g. "Start date" or "Project lead".
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// The variable description, if the template has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The date (YYYY-MM-DD) or user GID to supply.
+    #[serde(default)]
+    pub value: Option<String>,
 }
 
 /// A portfolio with its nested items expanded.
@@ -161,6 +368,18 @@ pub struct PortfolioWithItems {
     pub portfolio: Resource,
     /// The items in the portfolio.
     pub items: Vec<PortfolioItemExpanded>,
+    /// Items that couldn't be fetched.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<PortfolioItemError>,
+}
+
+/// An error fetching a portfolio item.
+#[derive(Debug, Clone, Serialize)]
+pub struct PortfolioItemError {
+    /// The item that failed.
+    pub item: PortfolioItem,
+    /// The error message.
+    pub error: String,
 }
 
 /// An expanded portfolio item with full details.
@@ -188,9 +407,48 @@ pub struct TaskWithContext {
     /// Tasks that depend on this task.
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub dependents: Vec<TaskDependency>,
-    /// Comments on this task.
+    /// The most recent comments on this task, oldest first.
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub comments: Vec<Story>,
+    /// Whether older comments were left out of `comments`.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub has_more_comments: bool,
+}
+
+/// The current user with the workspaces they can access.
+#[derive(Debug, Clone, Serialize)]
+pub struct WhoAmI {
+    /// The authenticated user.
+    pub user: Resource,
+    /// Workspaces the user can access.
+    pub workspaces: Vec<Resource>,
+    /// The workspace used when a workspace GID is omitted, if one is configured or the
+    /// user has exactly one workspace. `None` when a configured name matches no workspace.
+    pub default_workspace_gid: Option<String>,
+}
+
+/// A project snapshot with its custom fields, recent status updates, and sections.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectExport {
+    /// The project details.
+    #[serde(flatten)]
+    pub project: Resource,
+    /// Custom fields configured on the project.
+    pub custom_field_settings: Vec<Resource>,
+    /// The project's most recent status updates.
+    pub status_updates: Vec<Resource>,
+    /// The project's sections, in project order, with their tasks.
+    pub sections: Vec<SectionExport>,
+}
+
+/// A section with its tasks.
+#[derive(Debug, Clone, Serialize)]
+pub struct SectionExport {
+    /// The section details.
+    #[serde(flatten)]
+    pub section: Resource,
+    /// Tasks in the section, each followed by its subtasks (up to the requested depth).
+    pub tasks: Vec<Resource>,
 }
 
 /// Response containing user favorites with full details.
@@ -213,6 +471,69 @@ pub struct FavoriteError {
     pub error: String,
 }
 
+/// Outcome of marking a set of tasks complete or incomplete.
+#[derive(Debug, Serialize)]
+pub struct CompleteResponse {
+    /// GIDs of the tasks that were updated.
+    pub updated: Vec<String>,
+    /// Tasks that couldn't be updated.
+    pub errors: Vec<TaskError>,
+}
+
+/// An error updating a single task.
+#[derive(Debug, Serialize)]
+pub struct TaskError {
+    /// The task that failed.
+    pub task_gid: String,
+    /// The error message.
+    pub error: String,
+}
+
+/// Aggregate counts over a set of tasks, returned instead of the task records.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct TaskSummary {
+    /// Number of tasks.
+    pub total: usize,
+    /// Number of completed tasks.
+    pub completed: usize,
+    /// Number of incomplete tasks.
+    pub incomplete: usize,
+    /// Number of incomplete tasks whose due date has passed.
+    pub overdue: usize,
+    /// Number of tasks per assignee name ("Unassigned" for tasks without one).
+    pub by_assignee: BTreeMap<String, usize>,
+}
+
+/// My Tasks entries that share an assignee section (e.g. "Today", "Upcoming").
+#[derive(Debug, Clone, Serialize)]
+pub struct SectionGroup {
+    /// The section as returned by Asana, or null for tasks outside any section.
+    pub section: Option<serde_json::Value>,
+    /// Tasks in the section, in My Tasks order.
+    pub tasks: Vec<Resource>,
+}
+
+/// A write request recorded by a dry-run client instead of being sent.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PlannedRequest {
+    /// The HTTP method: "POST", "PUT", or "DELETE".
+    pub method: &'static str,
+    /// The API path, without the base URL.
+    pub path: String,
+    /// The JSON body, if the request has one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<serde_json::Value>,
+}
+
+/// The writes a mutating tool call would have sent in dry-run mode.
+#[derive(Debug, Serialize)]
+pub struct DryRunResponse {
+    /// Always true, marking that nothing was changed.
+    pub dry_run: bool,
+    /// The write requests, in the order they would be sent.
+    pub requests: Vec<PlannedRequest>,
+}
+
 /// An async job reference.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Job {
@@ -227,15 +548,64 @@ pub struct Job {
     #[serde(default)]
     pub new_project: Option<Resource>,
 
+    /// The new task (if applicable).
+    #[serde(default)]
+    pub new_task: Option<Resource>,
+
     /// All other fields.
     #[serde(flatten)]
     pub fields: Map<String, serde_json::Value>,
 }
 
+/// A page of events from Asana's `/events` endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventsPage {
+    /// Events since the previous sync token, oldest first.
+    #[serde(default)]
+    pub data: Vec<serde_json::Value>,
+
+    /// Token to pass on the next request to receive only newer events.
+    pub sync: String,
+
+    /// Whether more events are waiting; request again with the new sync token.
+    #[serde(default)]
+    pub has_more: bool,
+}
+
+/// The result of one action in a batch request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchResult {
+    /// The HTTP status code of the sub-request.
+    pub status_code: u16,
+
+    /// Response headers of the sub-request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub headers: Option<Map<String, serde_json::Value>>,
+
+    /// The sub-request's response body (a `data` or `errors` envelope).
+    #[serde(default)]
+    pub body: serde_json::Value,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_batch_result_deserialization() {
+        let json = r#"{"status_code": 200, "headers": {}, "body": {"data": {"gid": "1"}}}"#;
+        let result: BatchResult = serde_json::from_str(json).unwrap();
+
+        assert_eq!(result.status_code, 200);
+        assert_eq!(result.body["data"]["gid"], "1");
+
+        let json = r#"{"status_code": 404, "body": {"errors": [{"message": "Not found"}]}}"#;
+        let result: BatchResult = serde_json::from_str(json).unwrap();
+
+        assert_eq!(result.status_code, 404);
+        assert!(result.headers.is_none());
+    }
+
     #[test]
     fn test_resource_deserialization() {
         let json = r#"{"gid": "123", "name": "Test", "custom_field": "value"}"#;
@@ -246,6 +616,44 @@ mod tests {
         assert_eq!(resource.fields.get("custom_field").unwrap(), "value");
     }
 
+    #[test]
+    fn test_task_typed_getters() {
+        let json = r#"{
+            "gid": "1", "name": "Write docs", "completed": false, "due_on": "2024-06-12",
+            "assignee": {"gid": "u1", "name": "Alice"}, "custom": {"x": 1}
+        }"#;
+        let task: Task = serde_json::from_str(json).unwrap();
+
+        assert_eq!(task.gid, "1");
+        assert_eq!(task.name(), Some("Write docs"));
+        assert_eq!(task.completed(), Some(false));
+        assert_eq!(task.due_on(), NaiveDate::from_ymd_opt(2024, 6, 12));
+        assert_eq!(task.due_at(), None);
+        assert_eq!(task.assignee().unwrap().name.as_deref(), Some("Alice"));
+        // Raw fields remain available
+        assert_eq!(task.fields["custom"]["x"], 1);
+
+        let unassigned: Task = serde_json::from_str(r#"{"gid": "2", "assignee": null}"#).unwrap();
+        assert!(unassigned.assignee().is_none());
+        assert!(unassigned.name().is_none());
+    }
+
+    #[test]
+    fn test_project_typed_getters() {
+        let json = r#"{"gid": "p1", "name": "Roadmap", "archived": true, "owner": {"gid": "u1"}}"#;
+        let project: Project = serde_json::from_str(json).unwrap();
+
+        assert_eq!(project.name(), Some("Roadmap"));
+        assert_eq!(project.archived(), Some(true));
+        assert_eq!(project.owner().unwrap().gid, "u1");
+        assert!(project.due_on().is_none());
+
+        // Serializes back to the same flat shape
+        let value = serde_json::to_value(&project).unwrap();
+        assert_eq!(value["gid"], "p1");
+        assert_eq!(value["name"], "Roadmap");
+    }
+
     #[test]
     fn test_portfolio_item_deserialization() {
         let json = r#"{"gid": "456", "resource_type": "project", "name": "My Project"}"#;